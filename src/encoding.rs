@@ -0,0 +1,347 @@
+//! Shift_JIS, CP932 and EUC-JP codec, with Half-width-kana in single bytes 0xA1-0xDF, or after SS2 on EUC-JP
+//!
+//! CP932 is the Microsoft code page with the NEC and IBM extensions and the user-defined area.
+//! Shift_JIS and EUC-JP take JIS X 0208 rows only, and JIS-style characters such as Wave-dash
+//! instead of Microsoft-style ones as jis2ms() and ms2jis() convert.
+//! JIS X 0212 after SS3 on EUC-JP is decoded, but never encoded.
+//! Yen-sign and Overline are encoded into 0x5C and 0x7E as in JIS X 0201, but decoded as ASCII.
+
+use std::char;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use cp932::CP932;
+use jis0212::JIS0212;
+use {consult, JIS2MS, MS2JIS, MarkStyle, Width, LigatureMode,
+     decompose, vsmark2full, small2large, hentaigana2kana, expand_squared,
+     expand_ligatures, to_fullwidth};
+
+lazy_static! {
+    //  Unicode to CP932, taking the first code of duplicates in the way of Windows,
+    //  where NEC-selected IBM extensions at 0xED40-0xEEFC come last.
+    static ref ENCODE: HashMap<char,u16> = {
+        let mut table = HashMap::with_capacity(CP932.len());
+        for nec_selected in &[false, true] {
+            for (p, &u) in CP932.iter().enumerate() {
+                let code = code_of(p);
+                if u == 0 || ((code >> 8) == 0xED || (code >> 8) == 0xEE) != *nec_selected { continue; }
+                table.entry(char::from_u32(u32::from(u)).unwrap()).or_insert(code);
+            }
+        }
+        table
+    };
+}
+
+/// Encodings of the codec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Shift_JIS of JIS X 0208 and JIS X 0201 kana
+    ShiftJis,
+    /// Windows code page 932
+    Cp932,
+    /// EUC-JP of JIS X 0208, JIS X 0201 kana and JIS X 0212
+    EucJp,
+}
+
+/// Ways to handle characters which cannot be converted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorMode {
+    /// Stop at the first error
+    #[default]
+    Strict,
+    /// Replace with "?" on encoding, and with U+FFFD on decoding
+    Replace,
+    /// Try the conversions of this crate on encoding, such as decompose() for ゔ and
+    /// expand_ligatures() for ㋿, then replace as Replace does. Same as Replace on decoding.
+    Fallback,
+}
+
+/// Error of the codec on the Strict mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodingError {
+    /// Bytes which make no character, at the byte offset of the input
+    Undecodable { byte: usize },
+    /// A character which the encoding lacks, at the byte offset of the input
+    Unencodable { byte: usize, ch: char },
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EncodingError::Undecodable { byte } =>
+                write!(f, "invalid byte sequence at byte {}", byte),
+            EncodingError::Unencodable { byte, ch } =>
+                write!(f, "'{}' (U+{:04X}) at byte {} cannot be encoded", ch, ch as u32, byte),
+        }
+    }
+}
+
+impl Error for EncodingError {}
+
+//  Index of CP932 for a pair of bytes
+fn pointer(lead: u8, trail: u8) -> Option<usize> {
+    let l = match lead {
+        0x81..=0x9F => lead - 0x81,
+        0xE0..=0xFC => lead - 0xC1,
+        _ => return None,
+    };
+    let t = match trail {
+        0x40..=0x7E => trail - 0x40,
+        0x80..=0xFC => trail - 0x41,
+        _ => return None,
+    };
+    Some(l as usize * 188 + t as usize)
+}
+
+//  Shift_JIS code for a JIS X 0208 code of row and cell in 0x21-0x7E
+fn jis2sjis(j1: u8, j2: u8) -> (u8, u8) {
+    let lead = ((j1 + 1) >> 1) + if j1 <= 0x5E { 0x70 } else { 0xB0 };
+    let trail = if j1 & 1 == 0 { j2 + 0x7E } else if j2 >= 0x60 { j2 + 0x20 } else { j2 + 0x1F };
+    (lead, trail)
+}
+
+//  JIS X 0208 code for a Shift_JIS code
+fn sjis2jis(lead: u8, trail: u8) -> (u8, u8) {
+    let row = (lead - if lead <= 0x9F { 0x70 } else { 0xB0 }) * 2;
+    match trail {
+        0x9F..=0xFC => (row, trail - 0x7E),
+        0x80..=0x9E => (row - 1, trail - 0x20),
+        _           => (row - 1, trail - 0x1F),
+    }
+}
+
+//  Pair of bytes for an index of CP932
+fn code_of(p: usize) -> u16 {
+    let (l, t) = ((p / 188) as u16, (p % 188) as u16);
+    let lead = if l < 0x1F { l + 0x81 } else { l + 0xC1 };
+    let trail = if t < 0x3F { t + 0x40 } else { t + 0x41 };
+    (lead << 8) | trail
+}
+
+impl Encoding {
+    //  Whether a lead byte is in the double-byte rows of the encoding
+    fn has_row(self, lead: u8) -> bool {
+        match self {
+            Encoding::ShiftJis | Encoding::EucJp =>
+                matches!(lead, 0x81..=0x84 | 0x88..=0x9F | 0xE0..=0xEA),
+            Encoding::Cp932 => true,
+        }
+    }
+
+    fn decode_pair(self, lead: u8, trail: u8) -> Option<char> {
+        let u = CP932[pointer(lead, trail)?];
+        if u == 0 || !self.has_row(lead) { return None; }
+        let c = char::from_u32(u32::from(u))?;
+        match self {
+            Encoding::ShiftJis | Encoding::EucJp => Some(consult(&MS2JIS, &c)),
+            Encoding::Cp932 => Some(c),
+        }
+    }
+
+    //  Character at the position of bytes, and the length of bytes it takes
+    fn decode_at(self, bytes: &[u8], i: usize) -> (Option<char>, usize) {
+        let b = bytes[i];
+        let next = bytes.get(i + 1).copied();
+        if self == Encoding::EucJp {
+            let euc = |x: Option<u8>| x.filter(|x| (0xA1..=0xFE).contains(x));
+            return match (b, next) {
+                (0x00..=0x7F, _) => (Some(b as char), 1),
+                (0x8E, Some(t @ 0xA1..=0xDF)) => (char::from_u32(u32::from(t) - 0xA1 + 0xFF61), 2),
+                (0x8F, _) => match (euc(next), euc(bytes.get(i + 2).copied())) {
+                    (Some(j1), Some(j2)) => {
+                        let u = JIS0212[(j1 - 0xA1) as usize * 94 + (j2 - 0xA1) as usize];
+                        (char::from_u32(u32::from(u)).filter(|_| u != 0), 3)
+                    },
+                    _ => (None, 1),
+                },
+                (0xA1..=0xFE, Some(t @ 0xA1..=0xFE)) => {
+                    let (lead, trail) = jis2sjis(b - 0x80, t - 0x80);
+                    (self.decode_pair(lead, trail), 2)
+                },
+                _ => (None, 1),
+            };
+        }
+        match (b, next) {
+            (0x00..=0x7F, _) => (Some(b as char), 1),
+            (0x80, _) if self == Encoding::Cp932 => (Some('\u{80}'), 1),
+            (0xA1..=0xDF, _) => (char::from_u32(u32::from(b) - 0xA1 + 0xFF61), 1),
+            (_, Some(t)) if pointer(b, t).is_some() => match self.decode_pair(b, t) {
+                Some(c)          => (Some(c), 2),
+                None if t < 0x80 => (None, 1),
+                None             => (None, 2),
+            },
+            _ => (None, 1),
+        }
+    }
+
+    //  Single byte or pair of bytes for a character
+    fn encode_char(self, c: char) -> Option<u16> {
+        if self == Encoding::EucJp {
+            return match c {
+                '\u{FF61}'..='\u{FF9F}' => Some(c as u16 - 0xFF61 + 0x8EA1),
+                _ => Encoding::ShiftJis.encode_char(c).map(|x| match x {
+                    0x00..=0x7F => x,
+                    _ => {
+                        let (j1, j2) = sjis2jis((x >> 8) as u8, x as u8);
+                        (u16::from(j1 | 0x80) << 8) | u16::from(j2 | 0x80)
+                    },
+                }),
+            };
+        }
+        match c {
+            '\u{00}'..='\u{7F}' => Some(c as u16),
+            '\u{A5}' => Some(0x5C),
+            '\u{203E}' => Some(0x7E),
+            '\u{80}' if self == Encoding::Cp932 => Some(0x80),
+            '\u{FF61}'..='\u{FF9F}' => Some(c as u16 - 0xFF61 + 0xA1),
+            _ => {
+                let c = match self {
+                    Encoding::Cp932 => c,
+                    _ => consult(&JIS2MS, &c),
+                };
+                ENCODE.get(&c).copied().filter(|x| self.has_row((x >> 8) as u8))
+            },
+        }
+    }
+
+    fn encode_str(self, s: &str) -> Option<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(s.len());
+        for c in s.chars() {
+            push_code(&mut bytes, self.encode_char(c)?);
+        }
+        Some(bytes)
+    }
+
+    //  Bytes of a character through the conversions of this crate
+    fn fallback(self, c: char) -> Option<Vec<u8>> {
+        let s = c.to_string();
+        let candidates = [
+            match self {
+                Encoding::Cp932 => consult(&JIS2MS, &c).to_string(),
+                _ => consult(&MS2JIS, &c).to_string(),
+            },
+            vsmark2full(&s),
+            decompose(&s, MarkStyle::Full),
+            small2large(&s),
+            hentaigana2kana(&s),
+            expand_ligatures(&s, LigatureMode::Abbreviated),
+            expand_squared(&s, Width::Full),
+            to_fullwidth(&s),
+        ];
+        candidates.iter().filter(|x| **x != s).find_map(|x| self.encode_str(x))
+    }
+}
+
+fn push_code(bytes: &mut Vec<u8>, code: u16) {
+    if code > 0xFF { bytes.push((code >> 8) as u8); }
+    bytes.push(code as u8);
+}
+
+/// Decode bytes of Shift_JIS, CP932 or EUC-JP into a string  [B1 DD 83 4A -> ｱﾝカ]
+/// # Examples
+/// ```
+/// use kana::encoding::{decode, Encoding, ErrorMode};
+/// assert_eq!(Ok("ｱﾝカ".to_string()), decode(b"\xB1\xDD\x83\x4A", Encoding::Cp932, ErrorMode::Strict));
+/// assert_eq!(Ok("～".to_string()), decode(b"\x81\x60", Encoding::Cp932, ErrorMode::Strict));
+/// assert_eq!(Ok("〜".to_string()), decode(b"\x81\x60", Encoding::ShiftJis, ErrorMode::Strict));
+/// assert_eq!(Ok("A\u{FFFD}".to_string()), decode(b"A\x81", Encoding::ShiftJis, ErrorMode::Replace));
+/// assert_eq!(Ok("ｱ亜丂".to_string()), decode(b"\x8E\xB1\xB0\xA1\x8F\xB0\xA1", Encoding::EucJp, ErrorMode::Strict));
+/// ```
+pub fn decode(bytes: &[u8], enc: Encoding, mode: ErrorMode) -> Result<String, EncodingError> {
+    let mut line = String::with_capacity(bytes.len() * 3 / 2);
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = enc.decode_at(bytes, i);
+        match (c, mode) {
+            (Some(c), _) => line.push(c),
+            (None, ErrorMode::Strict) => return Err(EncodingError::Undecodable { byte: i }),
+            (None, _) => line.push('\u{FFFD}'),
+        }
+        i += len;
+    }
+    Ok(line)
+}
+
+/// Encode a string into bytes of Shift_JIS, CP932 or EUC-JP  [ｱﾝカ -> B1 DD 83 4A]
+/// # Examples
+/// ```
+/// use kana::encoding::{encode, Encoding, ErrorMode};
+/// assert_eq!(Ok(b"\xB1\xDD\x83\x4A".to_vec()), encode("ｱﾝカ", Encoding::ShiftJis, ErrorMode::Strict));
+/// assert_eq!(Ok(b"\x82\xA4\x81\x4A".to_vec()), encode("ゔ", Encoding::Cp932, ErrorMode::Fallback));
+/// assert_eq!(Ok(b"?".to_vec()), encode("ゔ", Encoding::Cp932, ErrorMode::Replace));
+/// assert!(encode("ゔ", Encoding::Cp932, ErrorMode::Strict).is_err());
+/// assert_eq!(Ok(b"\x8E\xB1\xB0\xA1".to_vec()), encode("ｱ亜", Encoding::EucJp, ErrorMode::Strict));
+/// ```
+pub fn encode(s: &str, enc: Encoding, mode: ErrorMode) -> Result<Vec<u8>, EncodingError> {
+    let mut bytes = Vec::with_capacity(s.len());
+    for (byte, ch) in s.char_indices() {
+        if let Some(code) = enc.encode_char(ch) {
+            push_code(&mut bytes, code);
+            continue;
+        }
+        match mode {
+            ErrorMode::Strict => return Err(EncodingError::Unencodable { byte, ch }),
+            ErrorMode::Replace => bytes.push(b'?'),
+            ErrorMode::Fallback => match enc.fallback(ch) {
+                Some(x) => bytes.extend_from_slice(&x),
+                None    => bytes.push(b'?'),
+            },
+        }
+    }
+    Ok(bytes)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoding_t1() {
+        let strict = ErrorMode::Strict;
+        for (p, &u) in CP932.iter().enumerate() {
+            if u == 0 { continue; }
+            let c = char::from_u32(u32::from(u)).unwrap();
+            let code = ENCODE[&c];
+            assert_eq!(Ok(c.to_string()), decode(&code.to_be_bytes(), Encoding::Cp932, strict));
+            assert_eq!(Some(p), pointer((code_of(p) >> 8) as u8, code_of(p) as u8));
+        }
+        assert_eq!(Ok(vec![0xFA, 0x40]), encode("ⅰ", Encoding::Cp932, strict));
+        assert_eq!(Ok(vec![0x81, 0xCA]), encode("￢", Encoding::Cp932, strict));
+        assert_eq!(Ok(vec![0x81, 0x60, 0x81, 0x60]), encode("〜～", Encoding::ShiftJis, strict));
+        assert_eq!(Ok("〜".to_string()), decode(&[0x81, 0x60], Encoding::ShiftJis, strict));
+        assert_eq!(Ok(vec![0x5C, 0x7E]), encode("¥‾", Encoding::ShiftJis, strict));
+        assert_eq!(Err(EncodingError::Undecodable { byte: 1 }), decode(&[0x41, 0x87, 0x40], Encoding::ShiftJis, strict));
+        assert_eq!(Err(EncodingError::Unencodable { byte: 6, ch: 'ⅰ' }), encode("ｱｲⅰ", Encoding::ShiftJis, strict));
+        assert_eq!(Ok("\u{FFFD}A\u{FFFD}".to_string()), decode(&[0xFF, 0x41, 0x81], Encoding::Cp932, ErrorMode::Replace));
+    }
+
+    #[test]
+    fn encoding_t3() {
+        let strict = ErrorMode::Strict;
+        for j1 in 0x21..=0x7E {
+            for j2 in 0x21..=0x7E {
+                let (lead, trail) = jis2sjis(j1, j2);
+                assert_eq!((j1, j2), sjis2jis(lead, trail));
+            }
+        }
+        let euc = decode(b"\xA4\xAB\xA4\xCA\xA1\xC1\x8E\xB6\x8E\xC5", Encoding::EucJp, strict);
+        assert_eq!(Ok("かな〜ｶﾅ".to_string()), euc);
+        assert_eq!(Ok(b"\xA4\xAB\xA4\xCA\xA1\xC1\x8E\xB6\x8E\xC5".to_vec()),
+                   encode("かな～ｶﾅ", Encoding::EucJp, strict));
+        assert_eq!(Err(EncodingError::Undecodable { byte: 1 }), decode(b"A\x8E\x41", Encoding::EucJp, strict));
+        assert_eq!(Err(EncodingError::Undecodable { byte: 0 }), decode(b"\xAD\xA1", Encoding::EucJp, strict));
+        assert!(encode("丂", Encoding::EucJp, strict).is_err());
+        assert_eq!(Ok("\u{FFFD}A".to_string()), decode(b"\x8F\xA1\xA1A", Encoding::EucJp, ErrorMode::Replace));
+    }
+
+    #[test]
+    fn encoding_t2() {
+        let fallback = |s| encode(s, Encoding::Cp932, ErrorMode::Fallback).unwrap();
+        assert_eq!(encode("令和", Encoding::Cp932, ErrorMode::Strict).unwrap(), fallback("㋿"));
+        assert_eq!(encode("ク か ゛", Encoding::Cp932, ErrorMode::Strict).unwrap(), fallback("ㇰ ゕ \u{3099}"));
+        assert_eq!(b"?".to_vec(), fallback("😀"));
+        let sjis = encode("ⅰ∥", Encoding::ShiftJis, ErrorMode::Fallback).unwrap();
+        assert_eq!(Ok("?‖".to_string()), decode(&sjis, Encoding::ShiftJis, ErrorMode::Strict));
+    }
+}
@@ -0,0 +1,90 @@
+//! Fixed-length fields of records in Shift_JIS bytes
+
+use {kata2half, CH_VOICED_HALF, CH_SEMIVOICED_HALF};
+
+/// Spaces to fill fields on fit_sjis_bytes()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pad {
+    /// Space "\u{20}" of 1 byte
+    #[default]
+    Half,
+    /// Wide-space "\u{3000}" of 2 bytes, and a space for the last odd byte
+    Full,
+}
+
+//  Bytes of a character in Shift_JIS, where ASCII, Yen-sign, Overline
+//  and Half-width-kana take 1 byte and the others take 2.
+fn sjis_len(c: char) -> usize {
+    match c {
+        '\u{00}'..='\u{7F}' | '\u{A5}' | '\u{203E}' | '\u{FF61}'..='\u{FF9F}' => 1,
+        _ => 2,
+    }
+}
+
+/// Fit a string in the given bytes of Shift_JIS, and pad it  [ｶﾞｷﾞ, 3 -> "ｶﾞ "]
+///
+/// No character is split, and Half-width-kana are not separated from their "\u{FF9E}" and "\u{FF9F}".
+/// Characters which Shift_JIS lacks are counted as 2 bytes.
+/// # Examples
+/// ```
+/// use kana::Pad;
+/// assert_eq!("ｶﾞ ", kana::fit_sjis_bytes("ｶﾞｷﾞ", 3, Pad::Half));
+/// assert_eq!("アイ\u{3000} ", kana::fit_sjis_bytes("アイ", 7, Pad::Full));
+/// assert_eq!("AB ", kana::fit_sjis_bytes("AB漢字", 3, Pad::Half));
+/// ```
+pub fn fit_sjis_bytes(s: &str, n: usize, pad: Pad) -> String {
+    let mut total = 0;
+    let mut base = (0, 0);
+    let mut end = s.len();
+    for (i, c) in s.char_indices() {
+        let len = sjis_len(c);
+        let mark = c == CH_VOICED_HALF || c == CH_SEMIVOICED_HALF;
+        if total + len > n {
+            if mark { total = base.1; }
+            end = if mark { base.0 } else { i };
+            break;
+        }
+        if !mark { base = (i, total); }
+        total += len;
+    }
+    let rest = n - total;
+    let mut line = String::with_capacity(end + rest);
+    line.push_str(&s[..end]);
+    match pad {
+        Pad::Half => line.push_str(&" ".repeat(rest)),
+        Pad::Full => {
+            line.push_str(&"\u{3000}".repeat(rest / 2));
+            line.push_str(&" ".repeat(rest % 2));
+        },
+    }
+    line
+}
+
+/// Fit a string in the given bytes of Shift_JIS after kata2half(), and pad it  [ガギ, 3 -> "ｶﾞ "]
+/// # Examples
+/// ```
+/// use kana::Pad;
+/// assert_eq!("ｶﾞｷﾞ ", kana::fit_sjis_bytes_half("ガギ", 5, Pad::Half));
+/// ```
+pub fn fit_sjis_bytes_half(s: &str, n: usize, pad: Pad) -> String {
+    fit_sjis_bytes(&kata2half(s), n, pad)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_t1() {
+        assert_eq!("", fit_sjis_bytes("ｱ", 0, Pad::Half));
+        assert_eq!(" ", fit_sjis_bytes("ア", 1, Pad::Full));
+        assert_eq!("ﾊﾟ ", fit_sjis_bytes("ﾊﾟﾋﾟ", 3, Pad::Half));
+        assert_eq!("ﾊﾟﾋﾟ", fit_sjis_bytes("ﾊﾟﾋﾟ", 4, Pad::Half));
+        assert_eq!("¥100\u{3000}", fit_sjis_bytes("¥100", 6, Pad::Full));
+        assert_eq!("ｺｰﾋｰ ｾ", fit_sjis_bytes_half("コーヒー セット", 6, Pad::Half));
+        let s = "¥100‾ｶﾞｷﾞ 漢字　ＡＢ";
+        let bytes = ::encoding::encode(s, ::encoding::Encoding::ShiftJis, ::encoding::ErrorMode::Strict);
+        assert_eq!(Ok(s.chars().map(sjis_len).sum()), bytes.map(|x| x.len()));
+    }
+}
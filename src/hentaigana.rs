@@ -0,0 +1,327 @@
+//! Hentaigana[変体仮名] and archaic kana in Kana Supplement and Kana Extended-A
+
+use std::collections::HashMap;
+
+lazy_static! {
+    //  Hentaigana and archaic kana, and the modern kana of their Unicode names.
+    //  Those with several readings, such as KA-KE, take the first one.
+    static ref HENTAIGANA: HashMap<char,char> = [
+        ('\u{1B000}', '\u{30A8}'),   //  𛀀	1B000	エ	30A8	KATAKANA LETTER ARCHAIC E
+        ('\u{1B001}', '\u{3048}'),   //  𛀁	1B001	え	3048	HIRAGANA LETTER ARCHAIC YE
+        ('\u{1B002}', '\u{3042}'),   //  𛀂	1B002	あ	3042	A-1
+        ('\u{1B003}', '\u{3042}'),   //  𛀃	1B003	あ	3042	A-2
+        ('\u{1B004}', '\u{3042}'),   //  𛀄	1B004	あ	3042	A-3
+        ('\u{1B005}', '\u{3042}'),   //  𛀅	1B005	あ	3042	A-WO
+        ('\u{1B006}', '\u{3044}'),   //  𛀆	1B006	い	3044	I-1
+        ('\u{1B007}', '\u{3044}'),   //  𛀇	1B007	い	3044	I-2
+        ('\u{1B008}', '\u{3044}'),   //  𛀈	1B008	い	3044	I-3
+        ('\u{1B009}', '\u{3044}'),   //  𛀉	1B009	い	3044	I-4
+        ('\u{1B00A}', '\u{3046}'),   //  𛀊	1B00A	う	3046	U-1
+        ('\u{1B00B}', '\u{3046}'),   //  𛀋	1B00B	う	3046	U-2
+        ('\u{1B00C}', '\u{3046}'),   //  𛀌	1B00C	う	3046	U-3
+        ('\u{1B00D}', '\u{3046}'),   //  𛀍	1B00D	う	3046	U-4
+        ('\u{1B00E}', '\u{3046}'),   //  𛀎	1B00E	う	3046	U-5
+        ('\u{1B00F}', '\u{3048}'),   //  𛀏	1B00F	え	3048	E-2
+        ('\u{1B010}', '\u{3048}'),   //  𛀐	1B010	え	3048	E-3
+        ('\u{1B011}', '\u{3048}'),   //  𛀑	1B011	え	3048	E-4
+        ('\u{1B012}', '\u{3048}'),   //  𛀒	1B012	え	3048	E-5
+        ('\u{1B013}', '\u{3048}'),   //  𛀓	1B013	え	3048	E-6
+        ('\u{1B014}', '\u{304A}'),   //  𛀔	1B014	お	304A	O-1
+        ('\u{1B015}', '\u{304A}'),   //  𛀕	1B015	お	304A	O-2
+        ('\u{1B016}', '\u{304A}'),   //  𛀖	1B016	お	304A	O-3
+        ('\u{1B017}', '\u{304B}'),   //  𛀗	1B017	か	304B	KA-1
+        ('\u{1B018}', '\u{304B}'),   //  𛀘	1B018	か	304B	KA-2
+        ('\u{1B019}', '\u{304B}'),   //  𛀙	1B019	か	304B	KA-3
+        ('\u{1B01A}', '\u{304B}'),   //  𛀚	1B01A	か	304B	KA-4
+        ('\u{1B01B}', '\u{304B}'),   //  𛀛	1B01B	か	304B	KA-5
+        ('\u{1B01C}', '\u{304B}'),   //  𛀜	1B01C	か	304B	KA-6
+        ('\u{1B01D}', '\u{304B}'),   //  𛀝	1B01D	か	304B	KA-7
+        ('\u{1B01E}', '\u{304B}'),   //  𛀞	1B01E	か	304B	KA-8
+        ('\u{1B01F}', '\u{304B}'),   //  𛀟	1B01F	か	304B	KA-9
+        ('\u{1B020}', '\u{304B}'),   //  𛀠	1B020	か	304B	KA-10
+        ('\u{1B021}', '\u{304B}'),   //  𛀡	1B021	か	304B	KA-11
+        ('\u{1B022}', '\u{304B}'),   //  𛀢	1B022	か	304B	KA-KE
+        ('\u{1B023}', '\u{304D}'),   //  𛀣	1B023	き	304D	KI-1
+        ('\u{1B024}', '\u{304D}'),   //  𛀤	1B024	き	304D	KI-2
+        ('\u{1B025}', '\u{304D}'),   //  𛀥	1B025	き	304D	KI-3
+        ('\u{1B026}', '\u{304D}'),   //  𛀦	1B026	き	304D	KI-4
+        ('\u{1B027}', '\u{304D}'),   //  𛀧	1B027	き	304D	KI-5
+        ('\u{1B028}', '\u{304D}'),   //  𛀨	1B028	き	304D	KI-6
+        ('\u{1B029}', '\u{304D}'),   //  𛀩	1B029	き	304D	KI-7
+        ('\u{1B02A}', '\u{304D}'),   //  𛀪	1B02A	き	304D	KI-8
+        ('\u{1B02B}', '\u{304F}'),   //  𛀫	1B02B	く	304F	KU-1
+        ('\u{1B02C}', '\u{304F}'),   //  𛀬	1B02C	く	304F	KU-2
+        ('\u{1B02D}', '\u{304F}'),   //  𛀭	1B02D	く	304F	KU-3
+        ('\u{1B02E}', '\u{304F}'),   //  𛀮	1B02E	く	304F	KU-4
+        ('\u{1B02F}', '\u{304F}'),   //  𛀯	1B02F	く	304F	KU-5
+        ('\u{1B030}', '\u{304F}'),   //  𛀰	1B030	く	304F	KU-6
+        ('\u{1B031}', '\u{304F}'),   //  𛀱	1B031	く	304F	KU-7
+        ('\u{1B032}', '\u{3051}'),   //  𛀲	1B032	け	3051	KE-1
+        ('\u{1B033}', '\u{3051}'),   //  𛀳	1B033	け	3051	KE-2
+        ('\u{1B034}', '\u{3051}'),   //  𛀴	1B034	け	3051	KE-3
+        ('\u{1B035}', '\u{3051}'),   //  𛀵	1B035	け	3051	KE-4
+        ('\u{1B036}', '\u{3051}'),   //  𛀶	1B036	け	3051	KE-5
+        ('\u{1B037}', '\u{3051}'),   //  𛀷	1B037	け	3051	KE-6
+        ('\u{1B038}', '\u{3053}'),   //  𛀸	1B038	こ	3053	KO-1
+        ('\u{1B039}', '\u{3053}'),   //  𛀹	1B039	こ	3053	KO-2
+        ('\u{1B03A}', '\u{3053}'),   //  𛀺	1B03A	こ	3053	KO-3
+        ('\u{1B03B}', '\u{3053}'),   //  𛀻	1B03B	こ	3053	KO-KI
+        ('\u{1B03C}', '\u{3055}'),   //  𛀼	1B03C	さ	3055	SA-1
+        ('\u{1B03D}', '\u{3055}'),   //  𛀽	1B03D	さ	3055	SA-2
+        ('\u{1B03E}', '\u{3055}'),   //  𛀾	1B03E	さ	3055	SA-3
+        ('\u{1B03F}', '\u{3055}'),   //  𛀿	1B03F	さ	3055	SA-4
+        ('\u{1B040}', '\u{3055}'),   //  𛁀	1B040	さ	3055	SA-5
+        ('\u{1B041}', '\u{3055}'),   //  𛁁	1B041	さ	3055	SA-6
+        ('\u{1B042}', '\u{3055}'),   //  𛁂	1B042	さ	3055	SA-7
+        ('\u{1B043}', '\u{3055}'),   //  𛁃	1B043	さ	3055	SA-8
+        ('\u{1B044}', '\u{3057}'),   //  𛁄	1B044	し	3057	SI-1
+        ('\u{1B045}', '\u{3057}'),   //  𛁅	1B045	し	3057	SI-2
+        ('\u{1B046}', '\u{3057}'),   //  𛁆	1B046	し	3057	SI-3
+        ('\u{1B047}', '\u{3057}'),   //  𛁇	1B047	し	3057	SI-4
+        ('\u{1B048}', '\u{3057}'),   //  𛁈	1B048	し	3057	SI-5
+        ('\u{1B049}', '\u{3057}'),   //  𛁉	1B049	し	3057	SI-6
+        ('\u{1B04A}', '\u{3059}'),   //  𛁊	1B04A	す	3059	SU-1
+        ('\u{1B04B}', '\u{3059}'),   //  𛁋	1B04B	す	3059	SU-2
+        ('\u{1B04C}', '\u{3059}'),   //  𛁌	1B04C	す	3059	SU-3
+        ('\u{1B04D}', '\u{3059}'),   //  𛁍	1B04D	す	3059	SU-4
+        ('\u{1B04E}', '\u{3059}'),   //  𛁎	1B04E	す	3059	SU-5
+        ('\u{1B04F}', '\u{3059}'),   //  𛁏	1B04F	す	3059	SU-6
+        ('\u{1B050}', '\u{3059}'),   //  𛁐	1B050	す	3059	SU-7
+        ('\u{1B051}', '\u{3059}'),   //  𛁑	1B051	す	3059	SU-8
+        ('\u{1B052}', '\u{305B}'),   //  𛁒	1B052	せ	305B	SE-1
+        ('\u{1B053}', '\u{305B}'),   //  𛁓	1B053	せ	305B	SE-2
+        ('\u{1B054}', '\u{305B}'),   //  𛁔	1B054	せ	305B	SE-3
+        ('\u{1B055}', '\u{305B}'),   //  𛁕	1B055	せ	305B	SE-4
+        ('\u{1B056}', '\u{305B}'),   //  𛁖	1B056	せ	305B	SE-5
+        ('\u{1B057}', '\u{305D}'),   //  𛁗	1B057	そ	305D	SO-1
+        ('\u{1B058}', '\u{305D}'),   //  𛁘	1B058	そ	305D	SO-2
+        ('\u{1B059}', '\u{305D}'),   //  𛁙	1B059	そ	305D	SO-3
+        ('\u{1B05A}', '\u{305D}'),   //  𛁚	1B05A	そ	305D	SO-4
+        ('\u{1B05B}', '\u{305D}'),   //  𛁛	1B05B	そ	305D	SO-5
+        ('\u{1B05C}', '\u{305D}'),   //  𛁜	1B05C	そ	305D	SO-6
+        ('\u{1B05D}', '\u{305D}'),   //  𛁝	1B05D	そ	305D	SO-7
+        ('\u{1B05E}', '\u{305F}'),   //  𛁞	1B05E	た	305F	TA-1
+        ('\u{1B05F}', '\u{305F}'),   //  𛁟	1B05F	た	305F	TA-2
+        ('\u{1B060}', '\u{305F}'),   //  𛁠	1B060	た	305F	TA-3
+        ('\u{1B061}', '\u{305F}'),   //  𛁡	1B061	た	305F	TA-4
+        ('\u{1B062}', '\u{3061}'),   //  𛁢	1B062	ち	3061	TI-1
+        ('\u{1B063}', '\u{3061}'),   //  𛁣	1B063	ち	3061	TI-2
+        ('\u{1B064}', '\u{3061}'),   //  𛁤	1B064	ち	3061	TI-3
+        ('\u{1B065}', '\u{3061}'),   //  𛁥	1B065	ち	3061	TI-4
+        ('\u{1B066}', '\u{3061}'),   //  𛁦	1B066	ち	3061	TI-5
+        ('\u{1B067}', '\u{3061}'),   //  𛁧	1B067	ち	3061	TI-6
+        ('\u{1B068}', '\u{3061}'),   //  𛁨	1B068	ち	3061	TI-7
+        ('\u{1B069}', '\u{3064}'),   //  𛁩	1B069	つ	3064	TU-1
+        ('\u{1B06A}', '\u{3064}'),   //  𛁪	1B06A	つ	3064	TU-2
+        ('\u{1B06B}', '\u{3064}'),   //  𛁫	1B06B	つ	3064	TU-3
+        ('\u{1B06C}', '\u{3064}'),   //  𛁬	1B06C	つ	3064	TU-4
+        ('\u{1B06D}', '\u{3064}'),   //  𛁭	1B06D	つ	3064	TU-TO
+        ('\u{1B06E}', '\u{3066}'),   //  𛁮	1B06E	て	3066	TE-1
+        ('\u{1B06F}', '\u{3066}'),   //  𛁯	1B06F	て	3066	TE-2
+        ('\u{1B070}', '\u{3066}'),   //  𛁰	1B070	て	3066	TE-3
+        ('\u{1B071}', '\u{3066}'),   //  𛁱	1B071	て	3066	TE-4
+        ('\u{1B072}', '\u{3066}'),   //  𛁲	1B072	て	3066	TE-5
+        ('\u{1B073}', '\u{3066}'),   //  𛁳	1B073	て	3066	TE-6
+        ('\u{1B074}', '\u{3066}'),   //  𛁴	1B074	て	3066	TE-7
+        ('\u{1B075}', '\u{3066}'),   //  𛁵	1B075	て	3066	TE-8
+        ('\u{1B076}', '\u{3066}'),   //  𛁶	1B076	て	3066	TE-9
+        ('\u{1B077}', '\u{3068}'),   //  𛁷	1B077	と	3068	TO-1
+        ('\u{1B078}', '\u{3068}'),   //  𛁸	1B078	と	3068	TO-2
+        ('\u{1B079}', '\u{3068}'),   //  𛁹	1B079	と	3068	TO-3
+        ('\u{1B07A}', '\u{3068}'),   //  𛁺	1B07A	と	3068	TO-4
+        ('\u{1B07B}', '\u{3068}'),   //  𛁻	1B07B	と	3068	TO-5
+        ('\u{1B07C}', '\u{3068}'),   //  𛁼	1B07C	と	3068	TO-6
+        ('\u{1B07D}', '\u{3068}'),   //  𛁽	1B07D	と	3068	TO-RA
+        ('\u{1B07E}', '\u{306A}'),   //  𛁾	1B07E	な	306A	NA-1
+        ('\u{1B07F}', '\u{306A}'),   //  𛁿	1B07F	な	306A	NA-2
+        ('\u{1B080}', '\u{306A}'),   //  𛂀	1B080	な	306A	NA-3
+        ('\u{1B081}', '\u{306A}'),   //  𛂁	1B081	な	306A	NA-4
+        ('\u{1B082}', '\u{306A}'),   //  𛂂	1B082	な	306A	NA-5
+        ('\u{1B083}', '\u{306A}'),   //  𛂃	1B083	な	306A	NA-6
+        ('\u{1B084}', '\u{306A}'),   //  𛂄	1B084	な	306A	NA-7
+        ('\u{1B085}', '\u{306A}'),   //  𛂅	1B085	な	306A	NA-8
+        ('\u{1B086}', '\u{306A}'),   //  𛂆	1B086	な	306A	NA-9
+        ('\u{1B087}', '\u{306B}'),   //  𛂇	1B087	に	306B	NI-1
+        ('\u{1B088}', '\u{306B}'),   //  𛂈	1B088	に	306B	NI-2
+        ('\u{1B089}', '\u{306B}'),   //  𛂉	1B089	に	306B	NI-3
+        ('\u{1B08A}', '\u{306B}'),   //  𛂊	1B08A	に	306B	NI-4
+        ('\u{1B08B}', '\u{306B}'),   //  𛂋	1B08B	に	306B	NI-5
+        ('\u{1B08C}', '\u{306B}'),   //  𛂌	1B08C	に	306B	NI-6
+        ('\u{1B08D}', '\u{306B}'),   //  𛂍	1B08D	に	306B	NI-7
+        ('\u{1B08E}', '\u{306B}'),   //  𛂎	1B08E	に	306B	NI-TE
+        ('\u{1B08F}', '\u{306C}'),   //  𛂏	1B08F	ぬ	306C	NU-1
+        ('\u{1B090}', '\u{306C}'),   //  𛂐	1B090	ぬ	306C	NU-2
+        ('\u{1B091}', '\u{306C}'),   //  𛂑	1B091	ぬ	306C	NU-3
+        ('\u{1B092}', '\u{306D}'),   //  𛂒	1B092	ね	306D	NE-1
+        ('\u{1B093}', '\u{306D}'),   //  𛂓	1B093	ね	306D	NE-2
+        ('\u{1B094}', '\u{306D}'),   //  𛂔	1B094	ね	306D	NE-3
+        ('\u{1B095}', '\u{306D}'),   //  𛂕	1B095	ね	306D	NE-4
+        ('\u{1B096}', '\u{306D}'),   //  𛂖	1B096	ね	306D	NE-5
+        ('\u{1B097}', '\u{306D}'),   //  𛂗	1B097	ね	306D	NE-6
+        ('\u{1B098}', '\u{306D}'),   //  𛂘	1B098	ね	306D	NE-KO
+        ('\u{1B099}', '\u{306E}'),   //  𛂙	1B099	の	306E	NO-1
+        ('\u{1B09A}', '\u{306E}'),   //  𛂚	1B09A	の	306E	NO-2
+        ('\u{1B09B}', '\u{306E}'),   //  𛂛	1B09B	の	306E	NO-3
+        ('\u{1B09C}', '\u{306E}'),   //  𛂜	1B09C	の	306E	NO-4
+        ('\u{1B09D}', '\u{306E}'),   //  𛂝	1B09D	の	306E	NO-5
+        ('\u{1B09E}', '\u{306F}'),   //  𛂞	1B09E	は	306F	HA-1
+        ('\u{1B09F}', '\u{306F}'),   //  𛂟	1B09F	は	306F	HA-2
+        ('\u{1B0A0}', '\u{306F}'),   //  𛂠	1B0A0	は	306F	HA-3
+        ('\u{1B0A1}', '\u{306F}'),   //  𛂡	1B0A1	は	306F	HA-4
+        ('\u{1B0A2}', '\u{306F}'),   //  𛂢	1B0A2	は	306F	HA-5
+        ('\u{1B0A3}', '\u{306F}'),   //  𛂣	1B0A3	は	306F	HA-6
+        ('\u{1B0A4}', '\u{306F}'),   //  𛂤	1B0A4	は	306F	HA-7
+        ('\u{1B0A5}', '\u{306F}'),   //  𛂥	1B0A5	は	306F	HA-8
+        ('\u{1B0A6}', '\u{306F}'),   //  𛂦	1B0A6	は	306F	HA-9
+        ('\u{1B0A7}', '\u{306F}'),   //  𛂧	1B0A7	は	306F	HA-10
+        ('\u{1B0A8}', '\u{306F}'),   //  𛂨	1B0A8	は	306F	HA-11
+        ('\u{1B0A9}', '\u{3072}'),   //  𛂩	1B0A9	ひ	3072	HI-1
+        ('\u{1B0AA}', '\u{3072}'),   //  𛂪	1B0AA	ひ	3072	HI-2
+        ('\u{1B0AB}', '\u{3072}'),   //  𛂫	1B0AB	ひ	3072	HI-3
+        ('\u{1B0AC}', '\u{3072}'),   //  𛂬	1B0AC	ひ	3072	HI-4
+        ('\u{1B0AD}', '\u{3072}'),   //  𛂭	1B0AD	ひ	3072	HI-5
+        ('\u{1B0AE}', '\u{3072}'),   //  𛂮	1B0AE	ひ	3072	HI-6
+        ('\u{1B0AF}', '\u{3072}'),   //  𛂯	1B0AF	ひ	3072	HI-7
+        ('\u{1B0B0}', '\u{3075}'),   //  𛂰	1B0B0	ふ	3075	HU-1
+        ('\u{1B0B1}', '\u{3075}'),   //  𛂱	1B0B1	ふ	3075	HU-2
+        ('\u{1B0B2}', '\u{3075}'),   //  𛂲	1B0B2	ふ	3075	HU-3
+        ('\u{1B0B3}', '\u{3078}'),   //  𛂳	1B0B3	へ	3078	HE-1
+        ('\u{1B0B4}', '\u{3078}'),   //  𛂴	1B0B4	へ	3078	HE-2
+        ('\u{1B0B5}', '\u{3078}'),   //  𛂵	1B0B5	へ	3078	HE-3
+        ('\u{1B0B6}', '\u{3078}'),   //  𛂶	1B0B6	へ	3078	HE-4
+        ('\u{1B0B7}', '\u{3078}'),   //  𛂷	1B0B7	へ	3078	HE-5
+        ('\u{1B0B8}', '\u{3078}'),   //  𛂸	1B0B8	へ	3078	HE-6
+        ('\u{1B0B9}', '\u{3078}'),   //  𛂹	1B0B9	へ	3078	HE-7
+        ('\u{1B0BA}', '\u{307B}'),   //  𛂺	1B0BA	ほ	307B	HO-1
+        ('\u{1B0BB}', '\u{307B}'),   //  𛂻	1B0BB	ほ	307B	HO-2
+        ('\u{1B0BC}', '\u{307B}'),   //  𛂼	1B0BC	ほ	307B	HO-3
+        ('\u{1B0BD}', '\u{307B}'),   //  𛂽	1B0BD	ほ	307B	HO-4
+        ('\u{1B0BE}', '\u{307B}'),   //  𛂾	1B0BE	ほ	307B	HO-5
+        ('\u{1B0BF}', '\u{307B}'),   //  𛂿	1B0BF	ほ	307B	HO-6
+        ('\u{1B0C0}', '\u{307B}'),   //  𛃀	1B0C0	ほ	307B	HO-7
+        ('\u{1B0C1}', '\u{307B}'),   //  𛃁	1B0C1	ほ	307B	HO-8
+        ('\u{1B0C2}', '\u{307E}'),   //  𛃂	1B0C2	ま	307E	MA-1
+        ('\u{1B0C3}', '\u{307E}'),   //  𛃃	1B0C3	ま	307E	MA-2
+        ('\u{1B0C4}', '\u{307E}'),   //  𛃄	1B0C4	ま	307E	MA-3
+        ('\u{1B0C5}', '\u{307E}'),   //  𛃅	1B0C5	ま	307E	MA-4
+        ('\u{1B0C6}', '\u{307E}'),   //  𛃆	1B0C6	ま	307E	MA-5
+        ('\u{1B0C7}', '\u{307E}'),   //  𛃇	1B0C7	ま	307E	MA-6
+        ('\u{1B0C8}', '\u{307E}'),   //  𛃈	1B0C8	ま	307E	MA-7
+        ('\u{1B0C9}', '\u{307F}'),   //  𛃉	1B0C9	み	307F	MI-1
+        ('\u{1B0CA}', '\u{307F}'),   //  𛃊	1B0CA	み	307F	MI-2
+        ('\u{1B0CB}', '\u{307F}'),   //  𛃋	1B0CB	み	307F	MI-3
+        ('\u{1B0CC}', '\u{307F}'),   //  𛃌	1B0CC	み	307F	MI-4
+        ('\u{1B0CD}', '\u{307F}'),   //  𛃍	1B0CD	み	307F	MI-5
+        ('\u{1B0CE}', '\u{307F}'),   //  𛃎	1B0CE	み	307F	MI-6
+        ('\u{1B0CF}', '\u{307F}'),   //  𛃏	1B0CF	み	307F	MI-7
+        ('\u{1B0D0}', '\u{3080}'),   //  𛃐	1B0D0	む	3080	MU-1
+        ('\u{1B0D1}', '\u{3080}'),   //  𛃑	1B0D1	む	3080	MU-2
+        ('\u{1B0D2}', '\u{3080}'),   //  𛃒	1B0D2	む	3080	MU-3
+        ('\u{1B0D3}', '\u{3080}'),   //  𛃓	1B0D3	む	3080	MU-4
+        ('\u{1B0D4}', '\u{3081}'),   //  𛃔	1B0D4	め	3081	ME-1
+        ('\u{1B0D5}', '\u{3081}'),   //  𛃕	1B0D5	め	3081	ME-2
+        ('\u{1B0D6}', '\u{3081}'),   //  𛃖	1B0D6	め	3081	ME-MA
+        ('\u{1B0D7}', '\u{3082}'),   //  𛃗	1B0D7	も	3082	MO-1
+        ('\u{1B0D8}', '\u{3082}'),   //  𛃘	1B0D8	も	3082	MO-2
+        ('\u{1B0D9}', '\u{3082}'),   //  𛃙	1B0D9	も	3082	MO-3
+        ('\u{1B0DA}', '\u{3082}'),   //  𛃚	1B0DA	も	3082	MO-4
+        ('\u{1B0DB}', '\u{3082}'),   //  𛃛	1B0DB	も	3082	MO-5
+        ('\u{1B0DC}', '\u{3082}'),   //  𛃜	1B0DC	も	3082	MO-6
+        ('\u{1B0DD}', '\u{3084}'),   //  𛃝	1B0DD	や	3084	YA-1
+        ('\u{1B0DE}', '\u{3084}'),   //  𛃞	1B0DE	や	3084	YA-2
+        ('\u{1B0DF}', '\u{3084}'),   //  𛃟	1B0DF	や	3084	YA-3
+        ('\u{1B0E0}', '\u{3084}'),   //  𛃠	1B0E0	や	3084	YA-4
+        ('\u{1B0E1}', '\u{3084}'),   //  𛃡	1B0E1	や	3084	YA-5
+        ('\u{1B0E2}', '\u{3084}'),   //  𛃢	1B0E2	や	3084	YA-YO
+        ('\u{1B0E3}', '\u{3086}'),   //  𛃣	1B0E3	ゆ	3086	YU-1
+        ('\u{1B0E4}', '\u{3086}'),   //  𛃤	1B0E4	ゆ	3086	YU-2
+        ('\u{1B0E5}', '\u{3086}'),   //  𛃥	1B0E5	ゆ	3086	YU-3
+        ('\u{1B0E6}', '\u{3086}'),   //  𛃦	1B0E6	ゆ	3086	YU-4
+        ('\u{1B0E7}', '\u{3088}'),   //  𛃧	1B0E7	よ	3088	YO-1
+        ('\u{1B0E8}', '\u{3088}'),   //  𛃨	1B0E8	よ	3088	YO-2
+        ('\u{1B0E9}', '\u{3088}'),   //  𛃩	1B0E9	よ	3088	YO-3
+        ('\u{1B0EA}', '\u{3088}'),   //  𛃪	1B0EA	よ	3088	YO-4
+        ('\u{1B0EB}', '\u{3088}'),   //  𛃫	1B0EB	よ	3088	YO-5
+        ('\u{1B0EC}', '\u{3088}'),   //  𛃬	1B0EC	よ	3088	YO-6
+        ('\u{1B0ED}', '\u{3089}'),   //  𛃭	1B0ED	ら	3089	RA-1
+        ('\u{1B0EE}', '\u{3089}'),   //  𛃮	1B0EE	ら	3089	RA-2
+        ('\u{1B0EF}', '\u{3089}'),   //  𛃯	1B0EF	ら	3089	RA-3
+        ('\u{1B0F0}', '\u{3089}'),   //  𛃰	1B0F0	ら	3089	RA-4
+        ('\u{1B0F1}', '\u{308A}'),   //  𛃱	1B0F1	り	308A	RI-1
+        ('\u{1B0F2}', '\u{308A}'),   //  𛃲	1B0F2	り	308A	RI-2
+        ('\u{1B0F3}', '\u{308A}'),   //  𛃳	1B0F3	り	308A	RI-3
+        ('\u{1B0F4}', '\u{308A}'),   //  𛃴	1B0F4	り	308A	RI-4
+        ('\u{1B0F5}', '\u{308A}'),   //  𛃵	1B0F5	り	308A	RI-5
+        ('\u{1B0F6}', '\u{308A}'),   //  𛃶	1B0F6	り	308A	RI-6
+        ('\u{1B0F7}', '\u{308A}'),   //  𛃷	1B0F7	り	308A	RI-7
+        ('\u{1B0F8}', '\u{308B}'),   //  𛃸	1B0F8	る	308B	RU-1
+        ('\u{1B0F9}', '\u{308B}'),   //  𛃹	1B0F9	る	308B	RU-2
+        ('\u{1B0FA}', '\u{308B}'),   //  𛃺	1B0FA	る	308B	RU-3
+        ('\u{1B0FB}', '\u{308B}'),   //  𛃻	1B0FB	る	308B	RU-4
+        ('\u{1B0FC}', '\u{308B}'),   //  𛃼	1B0FC	る	308B	RU-5
+        ('\u{1B0FD}', '\u{308B}'),   //  𛃽	1B0FD	る	308B	RU-6
+        ('\u{1B0FE}', '\u{308C}'),   //  𛃾	1B0FE	れ	308C	RE-1
+        ('\u{1B0FF}', '\u{308C}'),   //  𛃿	1B0FF	れ	308C	RE-2
+        ('\u{1B100}', '\u{308C}'),   //  𛄀	1B100	れ	308C	RE-3
+        ('\u{1B101}', '\u{308C}'),   //  𛄁	1B101	れ	308C	RE-4
+        ('\u{1B102}', '\u{308D}'),   //  𛄂	1B102	ろ	308D	RO-1
+        ('\u{1B103}', '\u{308D}'),   //  𛄃	1B103	ろ	308D	RO-2
+        ('\u{1B104}', '\u{308D}'),   //  𛄄	1B104	ろ	308D	RO-3
+        ('\u{1B105}', '\u{308D}'),   //  𛄅	1B105	ろ	308D	RO-4
+        ('\u{1B106}', '\u{308D}'),   //  𛄆	1B106	ろ	308D	RO-5
+        ('\u{1B107}', '\u{308D}'),   //  𛄇	1B107	ろ	308D	RO-6
+        ('\u{1B108}', '\u{308F}'),   //  𛄈	1B108	わ	308F	WA-1
+        ('\u{1B109}', '\u{308F}'),   //  𛄉	1B109	わ	308F	WA-2
+        ('\u{1B10A}', '\u{308F}'),   //  𛄊	1B10A	わ	308F	WA-3
+        ('\u{1B10B}', '\u{308F}'),   //  𛄋	1B10B	わ	308F	WA-4
+        ('\u{1B10C}', '\u{308F}'),   //  𛄌	1B10C	わ	308F	WA-5
+        ('\u{1B10D}', '\u{3090}'),   //  𛄍	1B10D	ゐ	3090	WI-1
+        ('\u{1B10E}', '\u{3090}'),   //  𛄎	1B10E	ゐ	3090	WI-2
+        ('\u{1B10F}', '\u{3090}'),   //  𛄏	1B10F	ゐ	3090	WI-3
+        ('\u{1B110}', '\u{3090}'),   //  𛄐	1B110	ゐ	3090	WI-4
+        ('\u{1B111}', '\u{3090}'),   //  𛄑	1B111	ゐ	3090	WI-5
+        ('\u{1B112}', '\u{3091}'),   //  𛄒	1B112	ゑ	3091	WE-1
+        ('\u{1B113}', '\u{3091}'),   //  𛄓	1B113	ゑ	3091	WE-2
+        ('\u{1B114}', '\u{3091}'),   //  𛄔	1B114	ゑ	3091	WE-3
+        ('\u{1B115}', '\u{3091}'),   //  𛄕	1B115	ゑ	3091	WE-4
+        ('\u{1B116}', '\u{3092}'),   //  𛄖	1B116	を	3092	WO-1
+        ('\u{1B117}', '\u{3092}'),   //  𛄗	1B117	を	3092	WO-2
+        ('\u{1B118}', '\u{3092}'),   //  𛄘	1B118	を	3092	WO-3
+        ('\u{1B119}', '\u{3092}'),   //  𛄙	1B119	を	3092	WO-4
+        ('\u{1B11A}', '\u{3092}'),   //  𛄚	1B11A	を	3092	WO-5
+        ('\u{1B11B}', '\u{3092}'),   //  𛄛	1B11B	を	3092	WO-6
+        ('\u{1B11C}', '\u{3092}'),   //  𛄜	1B11C	を	3092	WO-7
+        ('\u{1B11D}', '\u{3093}'),   //  𛄝	1B11D	ん	3093	N-MU-MO-1
+        ('\u{1B11E}', '\u{3093}'),   //  𛄞	1B11E	ん	3093	N-MU-MO-2
+        ('\u{1B11F}', '\u{3046}'),   //  𛄟	1B11F	う	3046	HIRAGANA LETTER ARCHAIC WU
+        ('\u{1B120}', '\u{30A4}'),   //  𛄠	1B120	イ	30A4	KATAKANA LETTER ARCHAIC YI
+        ('\u{1B121}', '\u{30A8}'),   //  𛄡	1B121	エ	30A8	KATAKANA LETTER ARCHAIC YE
+        ('\u{1B122}', '\u{30A6}'),   //  𛄢	1B122	ウ	30A6	KATAKANA LETTER ARCHAIC WU
+
+    ].iter().copied().collect();
+}
+
+/// Convert Hentaigana and archaic kana into modern ones  [𛀂𛂞𛄝 -> あはん]
+///
+/// Each character becomes the kana of its Unicode name, so
+/// Hentaigana become Hiragana and archaic Katakana become Katakana.
+/// # Examples
+/// ```
+/// assert_eq!("あはん エ", kana::hentaigana2kana("\u{1B002}\u{1B09E}\u{1B11D} \u{1B000}"));
+/// ```
+pub fn hentaigana2kana(s: &str) -> String {
+    s.chars().map(|c| *HENTAIGANA.get(&c).unwrap_or(&c)).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hentaigana_t1() {
+        assert_eq!(0x1B123 - 0x1B000, HENTAIGANA.len());
+        assert_eq!("かやゑを", hentaigana2kana("\u{1B022}\u{1B0E2}\u{1B112}\u{1B116}"));
+        assert_eq!("かな", hentaigana2kana("かな"));
+    }
+}
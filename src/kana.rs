@@ -1,7 +1,7 @@
 
 //! Converters of troublesome characters included in Japanese texts.
 //!
-//! * Half-width-kana[半角ｶﾅ;HANKAKU KANA] -> normal Katakana
+//! * Half-width-kana[半角ｶﾅ;HANKAKU KANA] <-> normal Katakana
 //! * Wide-alphanumeric[全角英数;ZENKAKU EISU] <-> normal ASCII
 //!
 //! # Example
@@ -30,6 +30,29 @@ use std::char;
 use std::collections::HashMap;
 use regex::Regex;
 
+mod hentaigana;
+mod squared;
+mod romaji;
+mod mora;
+mod script;
+mod width;
+mod field;
+mod validate;
+pub mod zengin;
+pub mod encoding;
+mod cp932;
+mod jis0212;
+pub use hentaigana::hentaigana2kana;
+pub use squared::expand_squared;
+pub use mora::{morae, Morae, Mora, MoraKind};
+pub use script::{is_hiragana, is_katakana, is_halfwidth_kana, is_kanji, is_fullwidth_ascii,
+                 is_voiced_mark, script_runs, Script, ScriptRuns};
+pub use width::{char_width, display_width, display_width_with, truncate_to_width, pad_to_width,
+                AmbiguousWidth};
+pub use field::{fit_sjis_bytes, fit_sjis_bytes_half, Pad};
+pub use validate::{validate, Allowed, Fix, Violation};
+pub use romaji::{romaji2hira, romaji2kata, kana2romaji, RomajiOptions, RomajiSystem, LongVowel};
+
 //  0x3099  combining  ゙
 //  0x309A  combining  ゚
 //  0x309B  fullwidth ゛
@@ -44,16 +67,24 @@ const CH_VOICED_FULL:      char = '\u{309B}';
 const CH_SEMIVOICED_FULL:  char = '\u{309C}';
 const CH_VOICED_HALF:      char = '\u{FF9E}';
 const CH_SEMIVOICED_HALF:  char = '\u{FF9F}';
-const CH_SPACE:            char = '\u{20}';
 
-const VOICED_COMBI:          &'static str = "\u{3099}";
-const SEMIVOICED_COMBI:      &'static str = "\u{309A}";
-const VOICED_WITH_SPACE:     &'static str = "\u{20}\u{3099}";
-const SEMIVOICED_WITH_SPACE: &'static str = "\u{20}\u{309A}";
+const VOICED_COMBI:          &str = "\u{3099}";
+const SEMIVOICED_COMBI:      &str = "\u{309A}";
+const VOICED_WITH_SPACE:     &str = "\u{20}\u{3099}";
+const SEMIVOICED_WITH_SPACE: &str = "\u{20}\u{309A}";
+const VOICED_FULL:           &str = "\u{309B}";
+const SEMIVOICED_FULL:       &str = "\u{309C}";
+const VOICED_HALF:           &str = "\u{FF9E}";
+const SEMIVOICED_HALF:       &str = "\u{FF9F}";
+
+//  kana that can be followed by small ya/yu/yo
+const I_ROWS: &str = "きぎしじちぢにひびぴみりキギシジチヂニヒビピミリｷｼﾁﾆﾋﾐﾘ";
+
+const ITERATION_MARKS: &str = "ゝゞヽヾ々〻";
 
-const RE_VOICED_MARKS: &'static str
+const RE_VOICED_MARKS: &str
     = r"(?:\x20??\x{3099}|\x{309B}|\x{FF9E})";
-const RE_SEMIVOICED_MARKS: &'static str
+const RE_SEMIVOICED_MARKS: &str
     = r"(?:\x20??\x{309A}|\x{309C}|\x{FF9F})";
 
 lazy_static! {
@@ -221,6 +252,295 @@ lazy_static! {
         //('\u{FF9E}', '\u{309B}'),   //  ﾞ	FF9E	゛	309B
         //('\u{FF9F}', '\u{309C}'),   //  ﾟ	FF9F	゜	309C
     ].iter().copied().collect();
+
+    //  Characters that JIS X 0201 cannot express, and their substitutes
+    static ref FALLBACK_HALVES: HashMap<char,&'static str> = [
+        ('\u{30EE}', "\u{FF9C}"),           //  ヮ	30EE	ﾜ	FF9C
+        ('\u{30F0}', "\u{FF72}"),           //  ヰ	30F0	ｲ	FF72
+        ('\u{30F1}', "\u{FF74}"),           //  ヱ	30F1	ｴ	FF74
+        ('\u{30F5}', "\u{FF76}"),           //  ヵ	30F5	ｶ	FF76
+        ('\u{30F6}', "\u{FF79}"),           //  ヶ	30F6	ｹ	FF79
+        ('\u{30F8}', "\u{FF72}\u{FF9E}"),   //  ヸ	30F8	ｲﾞ	FF72 FF9E
+        ('\u{30F9}', "\u{FF74}\u{FF9E}"),   //  ヹ	30F9	ｴﾞ	FF74 FF9E
+        ('\u{309B}', "\u{FF9E}"),           //  ゛	309B	ﾞ	FF9E
+        ('\u{309C}', "\u{FF9F}"),           //  ゜	309C	ﾟ	FF9F
+    ].iter().copied().collect();
+
+    static ref KATA_HALVES: HashMap<char,String> = {
+        let mut table: HashMap<char,String> = FALLBACK_HALVES.iter()
+            .map(|(k, v)| (*k, v.to_string())).collect();
+        for (h, k) in HALVES.iter() {
+            table.insert(*k, h.to_string());
+        }
+        for (h, k) in VOICED_HALVES.iter() {
+            table.insert(*k, format!("{}{}", h, CH_VOICED_HALF));
+        }
+        for (h, k) in SEMIVOICED_HALVES.iter() {
+            table.insert(*k, format!("{}{}", h, CH_SEMIVOICED_HALF));
+        }
+        table
+    };
+
+    static ref SMALLS: HashMap<char,char> = [
+        ('\u{3041}', '\u{3042}'),   //  ぁ	3041	あ	3042
+        ('\u{3043}', '\u{3044}'),   //  ぃ	3043	い	3044
+        ('\u{3045}', '\u{3046}'),   //  ぅ	3045	う	3046
+        ('\u{3047}', '\u{3048}'),   //  ぇ	3047	え	3048
+        ('\u{3049}', '\u{304A}'),   //  ぉ	3049	お	304A
+        ('\u{3063}', '\u{3064}'),   //  っ	3063	つ	3064
+        ('\u{3083}', '\u{3084}'),   //  ゃ	3083	や	3084
+        ('\u{3085}', '\u{3086}'),   //  ゅ	3085	ゆ	3086
+        ('\u{3087}', '\u{3088}'),   //  ょ	3087	よ	3088
+        ('\u{308E}', '\u{308F}'),   //  ゎ	308E	わ	308F
+        ('\u{3095}', '\u{304B}'),   //  ゕ	3095	か	304B
+        ('\u{3096}', '\u{3051}'),   //  ゖ	3096	け	3051
+        ('\u{30A1}', '\u{30A2}'),   //  ァ	30A1	ア	30A2
+        ('\u{30A3}', '\u{30A4}'),   //  ィ	30A3	イ	30A4
+        ('\u{30A5}', '\u{30A6}'),   //  ゥ	30A5	ウ	30A6
+        ('\u{30A7}', '\u{30A8}'),   //  ェ	30A7	エ	30A8
+        ('\u{30A9}', '\u{30AA}'),   //  ォ	30A9	オ	30AA
+        ('\u{30C3}', '\u{30C4}'),   //  ッ	30C3	ツ	30C4
+        ('\u{30E3}', '\u{30E4}'),   //  ャ	30E3	ヤ	30E4
+        ('\u{30E5}', '\u{30E6}'),   //  ュ	30E5	ユ	30E6
+        ('\u{30E7}', '\u{30E8}'),   //  ョ	30E7	ヨ	30E8
+        ('\u{30EE}', '\u{30EF}'),   //  ヮ	30EE	ワ	30EF
+        ('\u{30F5}', '\u{30AB}'),   //  ヵ	30F5	カ	30AB
+        ('\u{30F6}', '\u{30B1}'),   //  ヶ	30F6	ケ	30B1
+        ('\u{FF67}', '\u{FF71}'),   //  ｧ	FF67	ｱ	FF71
+        ('\u{FF68}', '\u{FF72}'),   //  ｨ	FF68	ｲ	FF72
+        ('\u{FF69}', '\u{FF73}'),   //  ｩ	FF69	ｳ	FF73
+        ('\u{FF6A}', '\u{FF74}'),   //  ｪ	FF6A	ｴ	FF74
+        ('\u{FF6B}', '\u{FF75}'),   //  ｫ	FF6B	ｵ	FF75
+        ('\u{FF6C}', '\u{FF94}'),   //  ｬ	FF6C	ﾔ	FF94
+        ('\u{FF6D}', '\u{FF95}'),   //  ｭ	FF6D	ﾕ	FF95
+        ('\u{FF6E}', '\u{FF96}'),   //  ｮ	FF6E	ﾖ	FF96
+        ('\u{FF6F}', '\u{FF82}'),   //  ｯ	FF6F	ﾂ	FF82
+        ('\u{31F0}', '\u{30AF}'),   //  ㇰ	31F0	ク	30AF
+        ('\u{31F1}', '\u{30B7}'),   //  ㇱ	31F1	シ	30B7
+        ('\u{31F2}', '\u{30B9}'),   //  ㇲ	31F2	ス	30B9
+        ('\u{31F3}', '\u{30C8}'),   //  ㇳ	31F3	ト	30C8
+        ('\u{31F4}', '\u{30CC}'),   //  ㇴ	31F4	ヌ	30CC
+        ('\u{31F5}', '\u{30CF}'),   //  ㇵ	31F5	ハ	30CF
+        ('\u{31F6}', '\u{30D2}'),   //  ㇶ	31F6	ヒ	30D2
+        ('\u{31F7}', '\u{30D5}'),   //  ㇷ	31F7	フ	30D5
+        ('\u{31F8}', '\u{30D8}'),   //  ㇸ	31F8	ヘ	30D8
+        ('\u{31F9}', '\u{30DB}'),   //  ㇹ	31F9	ホ	30DB
+        ('\u{31FA}', '\u{30E0}'),   //  ㇺ	31FA	ム	30E0
+        ('\u{31FB}', '\u{30E9}'),   //  ㇻ	31FB	ラ	30E9
+        ('\u{31FC}', '\u{30EA}'),   //  ㇼ	31FC	リ	30EA
+        ('\u{31FD}', '\u{30EB}'),   //  ㇽ	31FD	ル	30EB
+        ('\u{31FE}', '\u{30EC}'),   //  ㇾ	31FE	レ	30EC
+        ('\u{31FF}', '\u{30ED}'),   //  ㇿ	31FF	ロ	30ED
+        ('\u{1B132}', '\u{3053}'),   //  𛄲	1B132	こ	3053
+        ('\u{1B150}', '\u{3090}'),   //  𛅐	1B150	ゐ	3090
+        ('\u{1B151}', '\u{3091}'),   //  𛅑	1B151	ゑ	3091
+        ('\u{1B152}', '\u{3092}'),   //  𛅒	1B152	を	3092
+        ('\u{1B155}', '\u{30B3}'),   //  𛅕	1B155	コ	30B3
+        ('\u{1B164}', '\u{30F0}'),   //  𛅤	1B164	ヰ	30F0
+        ('\u{1B165}', '\u{30F1}'),   //  𛅥	1B165	ヱ	30F1
+        ('\u{1B166}', '\u{30F2}'),   //  𛅦	1B166	ヲ	30F2
+        ('\u{1B167}', '\u{30F3}'),   //  𛅧	1B167	ン	30F3
+    ].iter().copied().collect();
+
+    static ref YOONS: HashMap<char,char> = SMALLS.iter()
+        .filter(|&(_, v)| "やゆよヤユヨﾔﾕﾖ".contains(*v))
+        .map(|(k, v)| (*v, *k)).collect();
+
+    //  kana and their vowels, each row headed by the vowel
+    static ref VOWELS: HashMap<char,char> = [
+            "あぁあかがさざただなはばぱまゃやらゎわゕ",
+            "いぃいきぎしじちぢにひびぴみりゐ",
+            "うぅうくぐすずつづぬふぶぷむゅゆるゔ",
+            "えぇえけげせぜてでねへべぺめれゑゖ",
+            "おぉおこごそぞとどのほぼぽもょよろを",
+            "アァアカガサザタダナハバパマャヤラヮワヵヷ",
+            "イィイキギシジチヂニヒビピミリヰヸ",
+            "ウゥウクグスズツヅヌフブプムュユルヴ",
+            "エェエケゲセゼテデネヘベペメレヱヶヹ",
+            "オォオコゴソゾトドノホボポモョヨロヲヺ",
+            "ｱｧｱｶｻﾀﾅﾊﾏｬﾔﾗﾜ",
+            "ｲｨｲｷｼﾁﾆﾋﾐﾘ",
+            "ｳｩｳｸｽﾂﾇﾌﾑｭﾕﾙ",
+            "ｴｪｴｹｾﾃﾈﾍﾒﾚ",
+            "ｵｫｵｺｿﾄﾉﾎﾓｮﾖﾛｦ",
+    ].iter().flat_map(|row| {
+        let v = row.chars().next().unwrap();
+        row.chars().skip(1).map(move |c| (c, v))
+    }).collect();
+
+    static ref VOICED_WAS: HashMap<char,char> = [
+        ('\u{30F7}', '\u{30D0}'),   //  ヷ	30F7	バ	30D0
+        ('\u{30F8}', '\u{30D3}'),   //  ヸ	30F8	ビ	30D3
+        ('\u{30F9}', '\u{30D9}'),   //  ヹ	30F9	ベ	30D9
+        ('\u{30FA}', '\u{30DC}'),   //  ヺ	30FA	ボ	30DC
+    ].iter().copied().collect();
+
+    static ref MODERNS: HashMap<char,&'static str> = [
+        ('\u{3090}', "\u{3044}"),           //  ゐ	3090	い	3044
+        ('\u{3091}', "\u{3048}"),           //  ゑ	3091	え	3048
+        ('\u{30F0}', "\u{30A4}"),           //  ヰ	30F0	イ	30A4
+        ('\u{30F1}', "\u{30A8}"),           //  ヱ	30F1	エ	30A8
+        ('\u{30F8}', "\u{30F4}\u{30A3}"),   //  ヸ	30F8	ヴィ	30F4 30A3
+        ('\u{30F9}', "\u{30F4}\u{30A7}"),   //  ヹ	30F9	ヴェ	30F4 30A7
+    ].iter().copied().collect();
+
+    //  The same JIS X 0208 code points are decoded differently by JIS and CP932 tables
+    static ref JIS2MS: HashMap<char,char> = [
+        ('\u{301C}', '\u{FF5E}'),   //  〜	301C	～	FF5E
+        ('\u{2212}', '\u{FF0D}'),   //  −	2212	－	FF0D
+        ('\u{2016}', '\u{2225}'),   //  ‖	2016	∥	2225
+        ('\u{2014}', '\u{2015}'),   //  —	2014	―	2015
+        ('\u{00A2}', '\u{FFE0}'),   //  ¢	00A2	￠	FFE0
+        ('\u{00A3}', '\u{FFE1}'),   //  £	00A3	￡	FFE1
+        ('\u{00AC}', '\u{FFE2}'),   //  ¬	00AC	￢	FFE2
+    ].iter().copied().collect();
+
+    static ref MS2JIS: HashMap<char,char> =
+        JIS2MS.iter().map(|(k, v)| (*v, *k)).collect();
+
+    //  Narrow and Wide forms besides ASCII and kana, paired as (narrow, wide)
+    static ref WIDTHS: Vec<(char,char)> = {
+        let mut table: Vec<(char,char)> = (0x21..0x7F).map(|x| (
+            char::from_u32(x).unwrap(), char::from_u32(x + 0xFEE0).unwrap()
+        )).collect();
+        table.extend_from_slice(&[
+            ('\u{0020}', '\u{3000}'),   //   	0020	　	3000
+            ('\u{2985}', '\u{FF5F}'),   //  ⦅	2985	｟	FF5F
+            ('\u{2986}', '\u{FF60}'),   //  ⦆	2986	｠	FF60
+            ('\u{FFA0}', '\u{3164}'),   //  ﾠ	FFA0	ㅤ	3164
+            ('\u{FFA1}', '\u{3131}'),   //  ﾡ	FFA1	ㄱ	3131
+            ('\u{FFA2}', '\u{3132}'),   //  ﾢ	FFA2	ㄲ	3132
+            ('\u{FFA3}', '\u{3133}'),   //  ﾣ	FFA3	ㄳ	3133
+            ('\u{FFA4}', '\u{3134}'),   //  ﾤ	FFA4	ㄴ	3134
+            ('\u{FFA5}', '\u{3135}'),   //  ﾥ	FFA5	ㄵ	3135
+            ('\u{FFA6}', '\u{3136}'),   //  ﾦ	FFA6	ㄶ	3136
+            ('\u{FFA7}', '\u{3137}'),   //  ﾧ	FFA7	ㄷ	3137
+            ('\u{FFA8}', '\u{3138}'),   //  ﾨ	FFA8	ㄸ	3138
+            ('\u{FFA9}', '\u{3139}'),   //  ﾩ	FFA9	ㄹ	3139
+            ('\u{FFAA}', '\u{313A}'),   //  ﾪ	FFAA	ㄺ	313A
+            ('\u{FFAB}', '\u{313B}'),   //  ﾫ	FFAB	ㄻ	313B
+            ('\u{FFAC}', '\u{313C}'),   //  ﾬ	FFAC	ㄼ	313C
+            ('\u{FFAD}', '\u{313D}'),   //  ﾭ	FFAD	ㄽ	313D
+            ('\u{FFAE}', '\u{313E}'),   //  ﾮ	FFAE	ㄾ	313E
+            ('\u{FFAF}', '\u{313F}'),   //  ﾯ	FFAF	ㄿ	313F
+            ('\u{FFB0}', '\u{3140}'),   //  ﾰ	FFB0	ㅀ	3140
+            ('\u{FFB1}', '\u{3141}'),   //  ﾱ	FFB1	ㅁ	3141
+            ('\u{FFB2}', '\u{3142}'),   //  ﾲ	FFB2	ㅂ	3142
+            ('\u{FFB3}', '\u{3143}'),   //  ﾳ	FFB3	ㅃ	3143
+            ('\u{FFB4}', '\u{3144}'),   //  ﾴ	FFB4	ㅄ	3144
+            ('\u{FFB5}', '\u{3145}'),   //  ﾵ	FFB5	ㅅ	3145
+            ('\u{FFB6}', '\u{3146}'),   //  ﾶ	FFB6	ㅆ	3146
+            ('\u{FFB7}', '\u{3147}'),   //  ﾷ	FFB7	ㅇ	3147
+            ('\u{FFB8}', '\u{3148}'),   //  ﾸ	FFB8	ㅈ	3148
+            ('\u{FFB9}', '\u{3149}'),   //  ﾹ	FFB9	ㅉ	3149
+            ('\u{FFBA}', '\u{314A}'),   //  ﾺ	FFBA	ㅊ	314A
+            ('\u{FFBB}', '\u{314B}'),   //  ﾻ	FFBB	ㅋ	314B
+            ('\u{FFBC}', '\u{314C}'),   //  ﾼ	FFBC	ㅌ	314C
+            ('\u{FFBD}', '\u{314D}'),   //  ﾽ	FFBD	ㅍ	314D
+            ('\u{FFBE}', '\u{314E}'),   //  ﾾ	FFBE	ㅎ	314E
+            ('\u{FFC2}', '\u{314F}'),   //  ￂ	FFC2	ㅏ	314F
+            ('\u{FFC3}', '\u{3150}'),   //  ￃ	FFC3	ㅐ	3150
+            ('\u{FFC4}', '\u{3151}'),   //  ￄ	FFC4	ㅑ	3151
+            ('\u{FFC5}', '\u{3152}'),   //  ￅ	FFC5	ㅒ	3152
+            ('\u{FFC6}', '\u{3153}'),   //  ￆ	FFC6	ㅓ	3153
+            ('\u{FFC7}', '\u{3154}'),   //  ￇ	FFC7	ㅔ	3154
+            ('\u{FFCA}', '\u{3155}'),   //  ￊ	FFCA	ㅕ	3155
+            ('\u{FFCB}', '\u{3156}'),   //  ￋ	FFCB	ㅖ	3156
+            ('\u{FFCC}', '\u{3157}'),   //  ￌ	FFCC	ㅗ	3157
+            ('\u{FFCD}', '\u{3158}'),   //  ￍ	FFCD	ㅘ	3158
+            ('\u{FFCE}', '\u{3159}'),   //  ￎ	FFCE	ㅙ	3159
+            ('\u{FFCF}', '\u{315A}'),   //  ￏ	FFCF	ㅚ	315A
+            ('\u{FFD2}', '\u{315B}'),   //  ￒ	FFD2	ㅛ	315B
+            ('\u{FFD3}', '\u{315C}'),   //  ￓ	FFD3	ㅜ	315C
+            ('\u{FFD4}', '\u{315D}'),   //  ￔ	FFD4	ㅝ	315D
+            ('\u{FFD5}', '\u{315E}'),   //  ￕ	FFD5	ㅞ	315E
+            ('\u{FFD6}', '\u{315F}'),   //  ￖ	FFD6	ㅟ	315F
+            ('\u{FFD7}', '\u{3160}'),   //  ￗ	FFD7	ㅠ	3160
+            ('\u{FFDA}', '\u{3161}'),   //  ￚ	FFDA	ㅡ	3161
+            ('\u{FFDB}', '\u{3162}'),   //  ￛ	FFDB	ㅢ	3162
+            ('\u{FFDC}', '\u{3163}'),   //  ￜ	FFDC	ㅣ	3163
+            ('\u{00A2}', '\u{FFE0}'),   //  ¢	00A2	￠	FFE0
+            ('\u{00A3}', '\u{FFE1}'),   //  £	00A3	￡	FFE1
+            ('\u{00AC}', '\u{FFE2}'),   //  ¬	00AC	￢	FFE2
+            ('\u{00AF}', '\u{FFE3}'),   //  ¯	00AF	￣	FFE3
+            ('\u{00A6}', '\u{FFE4}'),   //  ¦	00A6	￤	FFE4
+            ('\u{00A5}', '\u{FFE5}'),   //  ¥	00A5	￥	FFE5
+            ('\u{20A9}', '\u{FFE6}'),   //  ₩	20A9	￦	FFE6
+            ('\u{FFE8}', '\u{2502}'),   //  ￨	FFE8	│	2502
+            ('\u{FFE9}', '\u{2190}'),   //  ￩	FFE9	←	2190
+            ('\u{FFEA}', '\u{2191}'),   //  ￪	FFEA	↑	2191
+            ('\u{FFEB}', '\u{2192}'),   //  ￫	FFEB	→	2192
+            ('\u{FFEC}', '\u{2193}'),   //  ￬	FFEC	↓	2193
+            ('\u{FFED}', '\u{25A0}'),   //  ￭	FFED	■	25A0
+            ('\u{FFEE}', '\u{25CB}'),   //  ￮	FFEE	○	25CB
+        ]);
+        table
+    };
+
+    static ref TO_WIDES: HashMap<char,char> = WIDTHS.iter().copied().collect();
+
+    static ref TO_NARROWS: HashMap<char,char> =
+        WIDTHS.iter().map(|&(n, w)| (w, n)).collect();
+
+    //  Ligatures of era names and company types, spelt as abbreviated and in full
+    static ref LIGATURES: HashMap<char,(&'static str,&'static str)> = [
+        ('\u{337B}', ("平成", "平成")),     //  ㍻	337B
+        ('\u{337C}', ("昭和", "昭和")),     //  ㍼	337C
+        ('\u{337D}', ("大正", "大正")),     //  ㍽	337D
+        ('\u{337E}', ("明治", "明治")),     //  ㍾	337E
+        ('\u{32FF}', ("令和", "令和")),     //  ㋿	32FF
+        ('\u{3231}', ("(株)", "株式会社")), //  ㈱	3231
+        ('\u{3232}', ("(有)", "有限会社")), //  ㈲	3232
+        ('\u{3233}', ("(社)", "社団法人")), //  ㈳	3233
+        ('\u{3234}', ("(名)", "合名会社")), //  ㈴	3234
+        ('\u{3236}', ("(財)", "財団法人")), //  ㈶	3236
+        ('\u{3239}', ("(代)", "代表")),     //  ㈹	3239
+        ('\u{323E}', ("(資)", "合資会社")), //  ㈾	323E
+        ('\u{323F}', ("(協)", "協同組合")), //  ㈿	323F
+        ('\u{337F}', ("株式会社", "株式会社")), //  ㍿	337F
+        ('\u{33CD}', ("KK", "株式会社")),   //  ㏍	33CD
+        ('\u{2121}', ("TEL", "TEL")),       //  ℡	2121
+        ('\u{2116}', ("No", "No")),         //  №	2116
+    ].iter().copied().collect();
+
+    static ref UNVOICES: HashMap<char,char> =
+        VOICES.iter().map(|(k, v)| (*v, *k)).collect();
+
+    static ref UNSEMIVOICES: HashMap<char,char> =
+        SEMIVOICES.iter().map(|(k, v)| (*v, *k)).collect();
+}
+
+/// Widths of output characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Width {
+    /// normal or Wide characters  [ア Ａ]
+    Full,
+    /// Half-width characters  [ｱ A]
+    Half,
+}
+
+/// Styles of separated Voiced-sound-marks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkStyle {
+    /// no space combining style "\u{3099}"
+    NoSpace,
+    /// space+combining style "\u{20}\u{3099}"
+    Combi,
+    /// full-width style "\u{309B}"
+    Full,
+    /// half-width style "\u{FF9E}"
+    Half,
+}
+
+impl MarkStyle {
+    fn marks(self) -> (&'static str, &'static str) {
+        match self {
+            MarkStyle::NoSpace => (VOICED_COMBI, SEMIVOICED_COMBI),
+            MarkStyle::Combi   => (VOICED_WITH_SPACE, SEMIVOICED_WITH_SPACE),
+            MarkStyle::Full    => (VOICED_FULL, SEMIVOICED_FULL),
+            MarkStyle::Half    => (VOICED_HALF, SEMIVOICED_HALF),
+        }
+    }
 }
 
 fn shift_code<F,G>(judge: F, convert: G, src: &str) -> String
@@ -251,22 +571,286 @@ pub fn ascii2wide(s: &str) -> String {
     shift_code(|x| 0x0020 < x && x < 0x007f, |x| x + 0xfee0, s)
 }
 
+/// Classes of characters converted by wide2ascii_with() and ascii2wide_with()
+///
+/// Characters in `include` and `exclude` may be written in either width,
+/// and `exclude` wins over `include` and the classes.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AsciiClasses {
+    /// 0-9
+    pub digits: bool,
+    /// A-Z
+    pub upper: bool,
+    /// a-z
+    pub lower: bool,
+    /// all the other printable ASCII characters except space
+    pub symbols: bool,
+    /// characters converted regardless of the classes
+    pub include: String,
+    /// characters never converted
+    pub exclude: String,
+}
+
+impl AsciiClasses {
+    /// All the classes, the same as wide2ascii() and ascii2wide()
+    pub fn all() -> Self {
+        AsciiClasses { digits: true, upper: true, lower: true, symbols: true,
+                       ..Default::default() }
+    }
+
+    fn accepts(&self, x: u32) -> bool {
+        let n = char::from_u32(x).unwrap();
+        let w = char::from_u32(x + 0xfee0).unwrap();
+        if self.exclude.contains(n) || self.exclude.contains(w) { return false; }
+        if self.include.contains(n) || self.include.contains(w) { return true; }
+        match n {
+            '0'..='9' => self.digits,
+            'A'..='Z' => self.upper,
+            'a'..='z' => self.lower,
+            _         => self.symbols,
+        }
+    }
+}
+
+/// Convert Wide-alphanumeric of the given classes into normal ASCII  [Ａ１！ -> A1！]
+/// # Examples
+/// ```
+/// use kana::AsciiClasses;
+/// let alnum = AsciiClasses { digits: true, upper: true, lower: true, ..Default::default() };
+/// assert_eq!("Rust1．6版（安定）！", kana::wide2ascii_with("Ｒｕｓｔ１．６版（安定）！", &alnum));
+///
+/// let alnum_dot = AsciiClasses { include: ".".to_string(), ..alnum };
+/// assert_eq!("Rust1.6版（安定）！", kana::wide2ascii_with("Ｒｕｓｔ１．６版（安定）！", &alnum_dot));
+/// ```
+pub fn wide2ascii_with(s: &str, classes: &AsciiClasses) -> String {
+    shift_code(|x| 0xff00 < x && x < 0xff5f && classes.accepts(x - 0xfee0),
+               |x| x - 0xfee0, s)
+}
+
+/// Convert normal ASCII characters of the given classes into Wide-alphanumeric  [A1! -> Ａ１!]
+/// # Examples
+/// ```
+/// use kana::AsciiClasses;
+/// let digits = AsciiClasses { digits: true, ..Default::default() };
+/// assert_eq!("No.１２３!", kana::ascii2wide_with("No.123!", &digits));
+/// ```
+pub fn ascii2wide_with(s: &str, classes: &AsciiClasses) -> String {
+    shift_code(|x| 0x0020 < x && x < 0x007f && classes.accepts(x),
+               |x| x + 0xfee0, s)
+}
+
+/// Convert every Wide character in Halfwidth and Fullwidth Forms into narrow one  [Ａ　ア￠ -> A ｱ¢]
+///
+/// Katakana are converted by kata2half(), and the others by one table of narrow/wide pairs
+/// including Wide-space, Wide-yen and Hangul.
+/// # Examples
+/// ```
+/// assert_eq!("Rust ｶﾞｲﾄﾞ ¥1,000 ⦅1⦆", kana::to_halfwidth("Ｒｕｓｔ　ガイド　￥１，０００　｟１｠"));
+/// ```
+pub fn to_halfwidth(s: &str) -> String {
+    kata2half(s).chars().map(|c| consult(&TO_NARROWS, &c)).collect()
+}
+
+/// Convert every narrow character in Halfwidth and Fullwidth Forms into Wide one  [A ｱ¢ -> Ａ　ア￠]
+///
+/// Half-width-kana are converted by half2kana_with() in full-width style "\u{309B}",
+/// and the others by one table of narrow/wide pairs including normal space, Half-width-yen and Hangul.
+/// # Examples
+/// ```
+/// assert_eq!("Ｒｕｓｔ　ガイド　￥１，０００　｟１｠", kana::to_fullwidth("Rust ｶﾞｲﾄﾞ ¥1,000 ⦅1⦆"));
+/// ```
+pub fn to_fullwidth(s: &str) -> String {
+    let ss: String = s.chars().map(|c| consult(&TO_WIDES, &c)).collect();
+    half2kana_with(&ss, MarkStyle::Full)
+}
+
 /// Convert Hiragana into Katakana  [あ -> ア]
 /// # Examples
 /// ```
-/// assert_eq!("イロハァィゥヴヵヶ", kana::hira2kata("いろはぁぃぅゔゕゖ"));
+/// assert_eq!("イロハァィゥヴヵヶヽヾ", kana::hira2kata("いろはぁぃぅゔゕゖゝゞ"));
 /// ```
 pub fn hira2kata(s: &str) -> String {
-    shift_code(|x| 0x3041 <= x && x <= 0x3096, |x| x + 0x0060, s)
+    shift_code(|x| char::from_u32(x).is_some_and(is_hiragana),
+               |x| match x {
+                   0x1B132 => 0x1B155,
+                   0x1B150..=0x1B152 => x + 0x0014,
+                   0x3041..=0x309E => x + 0x0060,
+                   _ => x,
+               }, s)
+}
+
+/// Options of kata2hira_with()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Kata2HiraOptions {
+    /// Replace Prolonged-sound-marks with vowels by expand_choon() before conversion
+    pub expand_choon: bool,
+    /// How to convert ヷヸヹヺ, which have no precomposed Hiragana
+    pub voiced_wa: VoicedWaPolicy,
+}
+
+/// Policies for ヷヸヹヺ on kata2hira_with()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VoicedWaPolicy {
+    /// Keep them as Katakana  [ヷ -> ヷ]
+    #[default]
+    Keep,
+    /// Decompose them into わゐゑを and Voiced-sound-marks in the given style  [ヷ -> わ゛]
+    Decompose(MarkStyle),
+    /// Approximate them with ばびべぼ  [ヷ -> ば]
+    Approximate,
 }
 
+
 /// Convert Katakana into Hiragana  [ア -> あ]
 /// # Examples
 /// ```
-/// assert_eq!("いろはぁぃぅゔゕゖ", kana::kata2hira("イロハァィゥヴヵヶ"));
+/// assert_eq!("いろはぁぃぅゔゕゖゝゞ", kana::kata2hira("イロハァィゥヴヵヶヽヾ"));
 /// ```
 pub fn kata2hira(s: &str) -> String {
-    shift_code(|x| 0x30A1 <= x && x <= 0x30F6, |x| x - 0x0060, s)
+    shift_code(|x| char::from_u32(x).is_some_and(is_katakana),
+               |x| match x {
+                   0x1B155 => 0x1B132,
+                   0x1B164..=0x1B166 => x - 0x0014,
+                   0x30A1..=0x30F6 | 0x30FD..=0x30FE => x - 0x0060,
+                   _ => x,
+               }, s)
+}
+
+/// Convert Katakana into Hiragana with options  [ラーメン -> らあめん]
+/// # Examples
+/// ```
+/// use kana::Kata2HiraOptions;
+/// let opts = Kata2HiraOptions { expand_choon: true, ..Default::default() };
+/// assert_eq!("らあめん", kana::kata2hira_with("ラーメン", opts));
+///
+/// use kana::{MarkStyle, VoicedWaPolicy};
+/// let opts = Kata2HiraOptions { voiced_wa: VoicedWaPolicy::Decompose(MarkStyle::Full),
+///                               ..Default::default() };
+/// assert_eq!("わ゛いおりん", kana::kata2hira_with("ヷイオリン", opts));
+/// ```
+pub fn kata2hira_with(s: &str, opts: Kata2HiraOptions) -> String {
+    let ss = if opts.expand_choon { expand_choon(s) } else { s.to_string() };
+    let mut line = String::with_capacity(ss.len());
+    for c in ss.chars() {
+        match opts.voiced_wa {
+            VoicedWaPolicy::Decompose(style) if VOICED_WAS.contains_key(&c) => {
+                line.push(consult(&UNVOICES, &c));
+                line.push_str(style.marks().0);
+            },
+            VoicedWaPolicy::Approximate => line.push(consult(&VOICED_WAS, &c)),
+            _ => line.push(c),
+        }
+    }
+    kata2hira(&line)
+}
+
+/// Replace obsolete kana with modern ones  [ゐゑヰヱ -> いえイエ]
+///
+/// ヸヹ become ヴィヴェ. を and ヲ are kept because they are still in use.
+/// # Examples
+/// ```
+/// assert_eq!("いど えびす ウイスキー", kana::modernize_kana("ゐど ゑびす ウヰスキー"));
+/// ```
+pub fn modernize_kana(s: &str) -> String {
+    let mut line = String::with_capacity(s.len());
+    for c in s.chars() {
+        match MODERNS.get(&c) {
+            Some(m) => line.push_str(m),
+            None    => line.push(c),
+        }
+    }
+    line
+}
+
+/// Replace Prolonged-sound-marks with the vowels of preceding kana  [ラーメン -> ラアメン]
+///
+/// Both "\u{30FC}" and half-width "\u{FF70}" are replaced, with the vowel
+/// written in the same script as the preceding kana.
+/// Prolonged-sound-marks after kana without vowels, such as ン and ッ, are left.
+/// # Examples
+/// ```
+/// assert_eq!("ラアメン らあめん キャア ｶｱﾄﾞ", kana::expand_choon("ラーメン らーめん キャー ｶｰﾄﾞ"));
+/// ```
+pub fn expand_choon(s: &str) -> String {
+    let mut line = String::with_capacity(s.len());
+    let mut vowel = None;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{30FC}' || c == '\u{FF70}' {
+            if let Some(v) = vowel {
+                line.push(v);
+                continue;
+            }
+        }
+        let spaced_mark = c == '\u{20}' && chars.peek().is_some_and(|&b|
+            b == CH_VOICED_COMBI || b == CH_SEMIVOICED_COMBI);
+        if !is_voiced_mark(c) && !spaced_mark { vowel = VOWELS.get(&c).copied(); }
+        line.push(c);
+    }
+    line
+}
+
+/// Expand Iteration-marks into the characters they repeat  [いすゞ こゝろ 時々 -> いすず こころ 時時]
+///
+/// ゝヽ repeat the preceding kana unvoiced, ゞヾ repeat it voiced, and 々〻 repeat it as it is.
+/// # Examples
+/// ```
+/// assert_eq!("いすず こころ 時時 ミスズ", kana::expand_iteration_marks("いすゞ こゝろ 時々 ミスヾ"));
+/// ```
+pub fn expand_iteration_marks(s: &str) -> String {
+    let mut line = String::with_capacity(s.len());
+    let mut prev = None;
+    for c in s.chars() {
+        if !ITERATION_MARKS.contains(c) {
+            line.push(c);
+            prev = if c.is_whitespace() { None } else { Some(c) };
+            continue;
+        }
+        match prev {
+            None    => line.push(c),
+            Some(p) => {
+                let unvoiced = consult(&UNSEMIVOICES, &consult(&UNVOICES, &p));
+                let r = match c {
+                    '\u{309D}' | '\u{30FD}' => unvoiced,
+                    '\u{309E}' | '\u{30FE}' => consult(&VOICES, &unvoiced),
+                    _ => p,
+                };
+                line.push(r);
+                prev = Some(r);
+            },
+        }
+    }
+    line
+}
+
+/// Convert small kana into large ones  [ぁッｮ -> あツﾖ]
+/// # Examples
+/// ```
+/// assert_eq!("キヤツプ ちよつと ｷﾔﾂﾌﾟ", kana::small2large("キャップ ちょっと ｷｬｯﾌﾟ"));
+/// ```
+pub fn small2large(s: &str) -> String {
+    s.chars().map(|c| consult(&SMALLS, &c)).collect()
+}
+
+/// Convert large ya/yu/yo following i-row kana into small ones  [キヤ -> キャ]
+///
+/// Only Yoon[拗音] contexts are restored, so any other small kana stay large.
+/// Note that words really spelt so, such as キヨウ[器用], are also converted.
+/// # Examples
+/// ```
+/// assert_eq!("キャツプ ちょつと ｷｬﾂﾌﾟ", kana::large2small("キヤツプ ちよつと ｷﾔﾂﾌﾟ"));
+/// ```
+pub fn large2small(s: &str) -> String {
+    let mut line = String::with_capacity(s.len());
+    let mut after_i = false;
+    for c in s.chars() {
+        match YOONS.get(&c) {
+            Some(v) if after_i => line.push(*v),
+            _                  => line.push(c),
+        }
+        if !is_voiced_mark(c) { after_i = I_ROWS.contains(c); }
+    }
+    line
 }
 
 macro_rules! push_content {
@@ -298,6 +882,19 @@ pub fn half2full(s: &str) -> String {
 /// assert_eq!("マツオ バショウ ア ゚", kana::half2kana("ﾏﾂｵ ﾊﾞｼｮｳ ｱﾟ"));
 /// ```
 pub fn half2kana(s: &str) -> String {
+    half2kana_with(s, MarkStyle::Combi)
+}
+
+/// Convert Half-width-kana into normal Katakana with diacritical marks combined,
+/// writing uncombinable marks in the given style  [ｱﾞﾊﾟ -> ア゙パ]
+/// # Examples
+/// ```
+/// use kana::MarkStyle;
+/// assert_eq!("ア\u{309A}パ", kana::half2kana_with("ｱﾟﾊﾟ", MarkStyle::NoSpace));
+/// assert_eq!("ア゜パ", kana::half2kana_with("ｱﾟﾊﾟ", MarkStyle::Full));
+/// ```
+pub fn half2kana_with(s: &str, style: MarkStyle) -> String {
+    let (vmark, svmark) = style.marks();
     let mut line = String::with_capacity(s.len());
     format!("{} ", s).chars().fold(None, |prev, b| {
         if let Some(a) = prev {
@@ -305,9 +902,11 @@ pub fn half2kana(s: &str) -> String {
                             VOICED_HALVES, line, a, b);
             push_content!(|b| b == CH_SEMIVOICED_HALF,
                             SEMIVOICED_HALVES, line, a, b);
-            if a == CH_VOICED_HALF ||
-                a == CH_SEMIVOICED_HALF { line.push(CH_SPACE); }
-            line.push(consult(&HALVES, &a));
+            match a {
+                CH_VOICED_HALF     => line.push_str(vmark),
+                CH_SEMIVOICED_HALF => line.push_str(svmark),
+                _                  => line.push(consult(&HALVES, &a)),
+            }
         }
         Some(b)
     } );
@@ -321,6 +920,23 @@ pub fn half2kana(s: &str) -> String {
 /// assert_eq!("ぴびんば", kana::combine("ひ゜ひ゛んは゛"));
 /// ```
 pub fn combine(s: &str) -> String {
+    enspace(&combine_marks(s))
+}
+
+/// Combine base characters and diacritical marks on Hiragana/Katakana,
+/// writing uncombinable marks in the given style  [かﾞあ゛ -> があ゙]
+/// # Examples
+/// ```
+/// use kana::MarkStyle;
+/// assert_eq!("があ\u{3099}", kana::combine_with("かﾞあ゛", MarkStyle::NoSpace));
+/// assert_eq!("があﾞ", kana::combine_with("かﾞあ ゙", MarkStyle::Half));
+/// ```
+pub fn combine_with(s: &str, style: MarkStyle) -> String {
+    let (vmark, svmark) = style.marks();
+    replace_marks(vmark, svmark, &combine_marks(s))
+}
+
+fn combine_marks(s: &str) -> String {
     let ss = despace(s);
     let mut line = String::with_capacity(ss.len());
     format!("{} ", ss).chars().fold(None, |prev, b| {
@@ -338,7 +954,41 @@ pub fn combine(s: &str) -> String {
         Some(b)
     } );
 
-    enspace(&line)
+    line
+}
+
+/// Convert normal Katakana into Half-width-kana  [アガパ -> ｱｶﾞﾊﾟ]
+///
+/// Characters that JIS X 0201 cannot express fall back to the nearest Half-width-kana:
+/// ヵヶヮ become ｶｹﾜ, ヰヱ become ｲｴ, ヸヹ become ｲﾞｴﾞ,
+/// other small kana such as ㇰ become large ones,
+/// and every separated Voiced-sound-mark becomes ﾞ or ﾟ.
+/// # Examples
+/// ```
+/// assert_eq!("ｶﾞｯｺｳ ﾊﾟｰﾃｨｰ｡ｹ", kana::kata2half("ガッコウ パーティー。ヶ"));
+/// ```
+pub fn kata2half(s: &str) -> String {
+    let ss = despace(s);
+    let mut line = String::with_capacity(ss.len());
+    for c in ss.chars() {
+        match KATA_HALVES.get(&c)
+                .or_else(|| SMALLS.get(&c).and_then(|l| KATA_HALVES.get(l))) {
+            Some(h) => line.push_str(h),
+            None    => line.push(c),
+        }
+    }
+    line
+}
+
+/// Convert Hiragana and Katakana into Half-width-kana  [あがぱ -> ｱｶﾞﾊﾟ]
+///
+/// Hiragana is converted by hira2kata() at first, then by kata2half().
+/// # Examples
+/// ```
+/// assert_eq!("ｶﾞｯｺｳ ｳﾞｧｲｵﾘﾝ", kana::hira2half("がっこう ゔぁいおりん"));
+/// ```
+pub fn hira2half(s: &str) -> String {
+    kata2half(&hira2kata(s))
 }
 
 fn consult(table: &HashMap<char,char>, c: &char) -> char {
@@ -393,7 +1043,44 @@ pub fn vsmark2full(s: &str) -> String {
 /// assert_eq!("ひ ゚ひ ゙んは ゙", kana::vsmark2combi("ひﾟひ゛んは ゙"));
 /// ```
 pub fn vsmark2combi(s: &str) -> String {
-    replace_marks(&VOICED_WITH_SPACE, &SEMIVOICED_WITH_SPACE, s)
+    replace_marks(VOICED_WITH_SPACE, SEMIVOICED_WITH_SPACE, s)
+}
+
+/// Convert all separated Voiced-sound-marks into no space combining style "\u{3099}"
+/// # Examples
+/// ```
+/// assert_eq!("ひ\u{309A}ひ\u{3099}んは\u{3099}", kana::vsmark2nospace("ひﾟひ゛んは ゙"));
+/// ```
+pub fn vsmark2nospace(s: &str) -> String {
+    replace_marks(VOICED_COMBI, SEMIVOICED_COMBI, s)
+}
+
+/// Decompose voiced Hiragana/Katakana into base characters and separated Voiced-sound-marks  [がパ -> か゛ハ゜]
+///
+/// The marks, including already separated ones, are written in the given style.
+/// # Examples
+/// ```
+/// use kana::MarkStyle;
+/// assert_eq!("か゛ハ゜", kana::decompose("がパ", MarkStyle::Full));
+/// assert_eq!("かﾞハﾟ", kana::decompose("がハ゜", MarkStyle::Half));
+/// assert_eq!("か\u{3099}ハ\u{309A}", kana::decompose("がパ", MarkStyle::NoSpace));
+/// ```
+pub fn decompose(s: &str, style: MarkStyle) -> String {
+    let (vmark, svmark) = style.marks();
+    let ss = replace_marks(vmark, svmark, s);
+    let mut line = String::with_capacity(ss.len() * 2);
+    for c in ss.chars() {
+        if let Some(v) = UNVOICES.get(&c) {
+            line.push(*v);
+            line.push_str(vmark);
+        } else if let Some(v) = UNSEMIVOICES.get(&c) {
+            line.push(*v);
+            line.push_str(svmark);
+        } else {
+            line.push(c);
+        }
+    }
+    line
 }
 
 /// Convert Wide-space into normal space    ["　" -> " "]
@@ -402,12 +1089,185 @@ pub fn nowidespace(s: &str) -> String { s.replace("\u{3000}", "\u{20}") }
 /// Convert normal space into Wide-space    [" " -> "　"]
 pub fn space2wide(s: &str) -> String { s.replace("\u{20}", "\u{3000}") }
 
+/// Styles of Japanese punctuation on punct_style()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PunctStyle {
+    /// Kuten/Touten style  [、。「」・]
+    Touten,
+    /// Wide comma/period style of official documents  [，．「」・]
+    Comma,
+    /// Half-width style  [､｡｢｣･]
+    Half,
+    /// ASCII style, leaving brackets and middle dots  [,.]
+    Ascii,
+}
+
+impl PunctStyle {
+    fn marks(self) -> (char, char, Option<(char, char, char)>) {
+        match self {
+            PunctStyle::Touten => ('、', '。', Some(('「', '」', '・'))),
+            PunctStyle::Comma  => ('，', '．', Some(('「', '」', '・'))),
+            PunctStyle::Half   => ('､', '｡', Some(('｢', '｣', '･'))),
+            PunctStyle::Ascii  => (',', '.', None),
+        }
+    }
+}
+
+fn is_digit(c: char) -> bool {
+    c.is_ascii_digit() || ('０'..='９').contains(&c)
+}
+
+/// Convert Japanese punctuation into the given style  [、。 <-> ，． <-> ､｡ <-> ,.]
+///
+/// Commas and periods between digits, such as "１．５" and "1,000", are kept as they are.
+/// ASCII commas and periods are converted only after non-ASCII characters.
+/// ASCII and wide commas and periods before alphanumerics, as in "資料.pdf" and "例.com", are kept too.
+/// # Examples
+/// ```
+/// use kana::PunctStyle;
+/// assert_eq!("値は１．５，単位はｍ．", kana::punct_style("値は１．５、単位はｍ。", PunctStyle::Comma));
+/// assert_eq!("｢はい｣､そうです｡", kana::punct_style("「はい」、そうです。", PunctStyle::Half));
+/// assert_eq!("はい、資料.pdfです。", kana::punct_style("はい,資料.pdfです.", PunctStyle::Touten));
+/// ```
+pub fn punct_style(s: &str, style: PunctStyle) -> String {
+    let (comma, period, marks) = style.marks();
+    let cs: Vec<char> = s.chars().collect();
+    let mut line = String::with_capacity(s.len());
+    for (i, &c) in cs.iter().enumerate() {
+        let prev = if 0 < i { Some(cs[i - 1]) } else { None };
+        let next = cs.get(i + 1).copied();
+        let numeric = prev.is_some_and(is_digit) && next.is_some_and(is_digit);
+        let ascii = (c == ',' || c == '.') && prev.is_none_or(|p| p.is_ascii());
+        let embedded = matches!(c, ',' | '.' | '，' | '．') && next.is_some_and(is_alnum);
+        let r = match (c, marks) {
+            _ if numeric || ascii || embedded => c,
+            ('、', _) | ('，', _) | ('､', _) | (',', _) => comma,
+            ('。', _) | ('．', _) | ('｡', _) | ('.', _) => period,
+            ('「', Some((o, _, _))) | ('｢', Some((o, _, _))) => o,
+            ('」', Some((_, e, _))) | ('｣', Some((_, e, _))) => e,
+            ('・', Some((_, _, d))) | ('･', Some((_, _, d))) => d,
+            _ => c,
+        };
+        line.push(r);
+    }
+    line
+}
+
+/// Dashes put between digits by normalize_dashes()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigitDash {
+    /// Hyphen-minus for phone and part numbers  [03-1234]
+    #[default]
+    Hyphen,
+    /// Minus sign for arithmetic  [5−3]
+    Minus,
+}
+
+const DASHES: &str = "-\u{2010}\u{2011}\u{2012}\u{2013}\u{2014}\u{2015}\u{2212}\u{2500}\u{FE63}\u{FF0D}\u{30FC}\u{FF70}";
+
+fn is_alnum(c: char) -> bool {
+    c.is_ascii_alphanumeric() || ('０'..='９').contains(&c) ||
+    ('Ａ'..='Ｚ').contains(&c) || ('ａ'..='ｚ').contains(&c)
+}
+
+/// Normalize Hyphens, Dashes, Minuses and Prolonged-sound-marks by neighbouring characters  [コ—ヒ- 03ー1234 -> コーヒー 03-1234]
+///
+/// After Katakana and not before digits or Latin letters, they become Prolonged-sound-mark "\u{30FC}",
+/// or "\u{FF70}" after Half-width-kana.
+/// Between digits, they become the given DigitDash, and so do they before digits as signs,
+/// except Prolonged-sound-marks already following kana, as in "コピー2部".
+/// Between Latin letters and digits, they become hyphen-minus "-".
+/// In the other contexts they are left as they are.
+/// # Examples
+/// ```
+/// use kana::DigitDash;
+/// assert_eq!("コーヒー ｺｰﾋｰ 03-1234 A-1", kana::normalize_dashes("コ—ヒ- ｺ-ﾋｰ 03ー1234 A―1", DigitDash::Hyphen));
+/// assert_eq!("−5−3", kana::normalize_dashes("－5ー3", DigitDash::Minus));
+/// ```
+pub fn normalize_dashes(s: &str, digit_dash: DigitDash) -> String {
+    let cs: Vec<char> = s.chars().collect();
+    let mut line = String::with_capacity(s.len());
+    for (i, &c) in cs.iter().enumerate() {
+        if !DASHES.contains(c) {
+            line.push(c);
+            continue;
+        }
+        let prev = line.chars().last();
+        let next = cs.get(i + 1).copied();
+        let d = match digit_dash {
+            DigitDash::Hyphen => '-',
+            DigitDash::Minus  => '\u{2212}',
+        };
+        let r = match (prev, next) {
+            (Some(p), Some(n)) if is_digit(p) && is_digit(n) => d,
+            (Some(p), Some(n)) if is_alnum(p) && is_alnum(n) => '-',
+            (Some(p), n) if is_halfwidth_kana(p) && !n.is_some_and(is_alnum) => '\u{FF70}',
+            (Some(p), n) if is_katakana(p) && !n.is_some_and(is_alnum) => '\u{30FC}',
+            (Some(p), _) if (c == '\u{30FC}' || c == '\u{FF70}') &&
+                (is_katakana(p) || is_halfwidth_kana(p)) => c,
+            (p, Some(n)) if is_digit(n) && !p.is_some_and(is_alnum) => d,
+            _ => c,
+        };
+        line.push(r);
+    }
+    line
+}
+
 /// Convert Wide-yen into Half-width-yen    ["￥" -> "¥"]
 pub fn nowideyen(s: &str) -> String { s.replace("\u{ffe5}", "\u{a5}") }
 
 /// Convert Half-width-yen into Wide-yen    ["¥" -> "￥"]
 pub fn yen2wide(s: &str) -> String { s.replace("\u{a5}", "\u{ffe5}") }
 
+/// Modes of expand_ligatures()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LigatureMode {
+    /// Expand into the letters drawn in the ligatures  [㈱ -> (株)]
+    #[default]
+    Abbreviated,
+    /// Expand company types in full  [㈱ -> 株式会社]
+    Spelled,
+}
+
+/// Expand ligatures of era names and company types  [㍻ ㈱ -> 平成 (株)]
+///
+/// Era names[㍻㍼㍽㍾㋿] are expanded in the same way by both modes.
+/// # Examples
+/// ```
+/// use kana::LigatureMode;
+/// assert_eq!("平成元年 (株)ABC", kana::expand_ligatures("㍻元年 ㈱ABC", LigatureMode::Abbreviated));
+/// assert_eq!("平成元年 株式会社ABC", kana::expand_ligatures("㍻元年 ㈱ABC", LigatureMode::Spelled));
+/// ```
+pub fn expand_ligatures(s: &str, mode: LigatureMode) -> String {
+    let mut line = String::with_capacity(s.len());
+    for c in s.chars() {
+        match (LIGATURES.get(&c), mode) {
+            (Some(&(a, _)), LigatureMode::Abbreviated) => line.push_str(a),
+            (Some(&(_, f)), LigatureMode::Spelled)     => line.push_str(f),
+            (None, _)                                  => line.push(c),
+        }
+    }
+    line
+}
+
+/// Convert JIS-style characters into Microsoft(CP932)-style ones    ["〜−‖—¢£¬" -> "～－∥―￠￡￢"]
+/// # Examples
+/// ```
+/// assert_eq!("１０～２０", kana::jis2ms("１０〜２０"));
+/// ```
+pub fn jis2ms(s: &str) -> String {
+    s.chars().map(|c| consult(&JIS2MS, &c)).collect()
+}
+
+/// Convert Microsoft(CP932)-style characters into JIS-style ones    ["～－∥―￠￡￢" -> "〜−‖—¢£¬"]
+/// # Examples
+/// ```
+/// assert_eq!("１０〜２０", kana::ms2jis("１０～２０"));
+/// ```
+pub fn ms2jis(s: &str) -> String {
+    s.chars().map(|c| consult(&MS2JIS, &c)).collect()
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -421,6 +1281,19 @@ mod tests {
         assert_eq!("かな", kata2hira("カナ"));
     }
 
+    #[test]
+    fn ascii_t1() {
+        let all = AsciiClasses::all();
+        assert_eq!(wide2ascii("！ｒｕｓｔ－０；"), wide2ascii_with("！ｒｕｓｔ－０；", &all));
+        assert_eq!(ascii2wide("!rust-0;"), ascii2wide_with("!rust-0;", &all));
+        let upper = AsciiClasses { upper: true, ..Default::default() };
+        assert_eq!("ＡbＣ1", ascii2wide_with("AbC1", &upper));
+        let no_bang = AsciiClasses { exclude: "！".to_string(), ..AsciiClasses::all() };
+        assert_eq!("R1！", wide2ascii_with("Ｒ１！", &no_bang));
+        assert_eq!("ＲＵＳＴ!", ascii2wide_with("RUST!", &no_bang));
+        assert_eq!("Ｒ1", wide2ascii_with("Ｒ１", &AsciiClasses { digits: true, ..Default::default() }));
+    }
+
     #[test]
     fn pub_fn_t2() {
         assert_eq!(" ", nowidespace("　"));
@@ -429,6 +1302,113 @@ mod tests {
         assert_eq!("￥", yen2wide("¥"));
     }
 
+    #[test]
+    fn jis2ms_t1() {
+        assert_eq!("～－∥―￠￡￢", jis2ms("〜−‖—¢£¬"));
+        assert_eq!("〜−‖—¢£¬", ms2jis("～－∥―￠￡￢"));
+    }
+
+    #[test]
+    fn pub_fn_t3() {
+        assert_eq!("あいうえおつやゆよわかけ", small2large("ぁぃぅぇぉっゃゅょゎゕゖ"));
+        assert_eq!("アイウエオツヤユヨワカケ", small2large("ァィゥェォッャュョヮヵヶ"));
+        assert_eq!("ｱｲｳｴｵﾂﾔﾕﾖ", small2large("ｧｨｩｪｫｯｬｭｮ"));
+        assert_eq!("ぎゃ ジュ ｷﾞｮ ヤ", large2small("ぎや ジユ ｷﾞﾖ ヤ"));
+        assert_eq!("し\u{3099}ゅ", large2small("し\u{3099}ゆ"));
+    }
+
+    #[test]
+    fn choon_t1() {
+        assert_eq!("ラアメン", expand_choon("ラーメン"));
+        assert_eq!("コオオヒイ", expand_choon("コーーヒー"));
+        assert_eq!("ヴウ ヴウ", expand_choon(&half2kana("ｳﾞｰ ｳﾞｰ")));
+        assert_eq!("ア ゙ア", expand_choon("ア ゙ー"));
+        assert_eq!("ンー ーア", expand_choon("ンー ーア"));
+        let opts = Kata2HiraOptions { expand_choon: true, ..Default::default() };
+        assert_eq!("きゃあ", kata2hira_with("キャー", opts));
+        assert_eq!("きゃー", kata2hira_with("キャー", Default::default()));
+    }
+
+    #[test]
+    fn historical_t1() {
+        let mut opts = Kata2HiraOptions::default();
+        assert_eq!("ヷゐヸゑヹをヺ", kata2hira_with("ヷヰヸヱヹヲヺ", opts));
+        opts.voiced_wa = VoicedWaPolicy::Decompose(MarkStyle::NoSpace);
+        assert_eq!("わ\u{3099}ゐ\u{3099}ゑ\u{3099}を\u{3099}", kata2hira_with("ヷヸヹヺ", opts));
+        opts.voiced_wa = VoicedWaPolicy::Approximate;
+        assert_eq!("ばびべぼがわ", kata2hira_with("ヷヸヹヺガワ", opts));
+        assert_eq!("いえイエヴィヴェをヲ", modernize_kana("ゐゑヰヱヸヹをヲ"));
+    }
+
+    #[test]
+    fn iteration_t1() {
+        assert_eq!("ヽヾ", hira2kata("ゝゞ"));
+        assert_eq!("ゝゞ", kata2hira("ヽヾ"));
+        assert_eq!("ぶふ ぶぶ すずず", expand_iteration_marks("ぶゝ ぶゞ すゞゞ"));
+        assert_eq!("人人人 〻ゝ", expand_iteration_marks("人々〻 〻ゝ"));
+        assert_eq!("ゝ", expand_iteration_marks("ゝ"));
+        assert_eq!("パハ ぱば ぱは", expand_iteration_marks("パヽ ぱゞ ぱゝ"));
+    }
+
+    #[test]
+    fn extended_t1() {
+        assert_eq!("\u{1B164}\u{1B165}\u{1B166}\u{1B155}", hira2kata("\u{1B150}\u{1B151}\u{1B152}\u{1B132}"));
+        assert_eq!("\u{1B150}\u{1B151}\u{1B152}\u{1B132}\u{1B167}", kata2hira("\u{1B164}\u{1B165}\u{1B166}\u{1B155}\u{1B167}"));
+        assert_eq!("クシフ\u{309A}ラ ゐを", small2large("ㇰㇱㇷ゚ㇻ \u{1B150}\u{1B152}"));
+        assert_eq!("ｸｼﾌﾟﾗ ｲｦﾝ", kata2half("ㇰㇱㇷ゚ㇻ \u{1B164}\u{1B166}\u{1B167}"));
+        assert_eq!("ｲｴ", hira2half("\u{1B150}\u{1B151}"));
+        assert_eq!("ゟ\u{1B001}", hira2kata("ゟ\u{1B001}"));
+        assert_eq!("ヿヷㇰ\u{1B000}", kata2hira("ヿヷㇰ\u{1B000}"));
+    }
+
+    #[test]
+    fn ligature_t1() {
+        let s = "㍾㍽㍼㍻㋿ ㈱㈲㍿㏍ ℡№";
+        assert_eq!("明治大正昭和平成令和 (株)(有)株式会社KK TELNo",
+                   expand_ligatures(s, LigatureMode::Abbreviated));
+        assert_eq!("明治大正昭和平成令和 株式会社有限会社株式会社株式会社 TELNo",
+                   expand_ligatures(s, LigatureMode::Spelled));
+    }
+
+    #[test]
+    fn width_t1() {
+        for (n, w) in WIDTHS.iter() {
+            assert_eq!(w.to_string(), to_fullwidth(&n.to_string()));
+            assert_eq!(n.to_string(), to_halfwidth(&w.to_string()));
+        }
+        assert_eq!("ｱﾞﾊﾟ ￨￩", to_halfwidth("ア゙パ　│←"));
+        assert_eq!("ア゛パ　│←", to_fullwidth("ｱﾞﾊﾟ ￨￩"));
+        assert_eq!("ン゛マ゜゛", to_fullwidth("ﾝﾞﾏﾟﾞ"));
+        assert!(to_fullwidth("ｱﾞ ﾝﾟ").chars().all(|c| !c.is_ascii()));
+        assert_eq!("ﾡﾱￓ", to_halfwidth("ㄱㅁㅜ"));
+    }
+
+    #[test]
+    fn punct_t1() {
+        let s = "「あ・い」、う。1,000円，で１．５";
+        assert_eq!("「あ・い」、う。1,000円、で１．５", punct_style(s, PunctStyle::Touten));
+        assert_eq!("「あ・い」，う．1,000円，で１．５", punct_style(s, PunctStyle::Comma));
+        assert_eq!("｢あ･い｣､う｡1,000円､で１．５", punct_style(s, PunctStyle::Half));
+        assert_eq!("「あ・い」,う.1,000円,で１．５", punct_style(s, PunctStyle::Ascii));
+        assert_eq!("Hi, Rust. あ｡", punct_style("Hi, Rust. あ.", PunctStyle::Half));
+        assert_eq!("資料.pdfと例.comです。", punct_style("資料.pdfと例.comです.", PunctStyle::Touten));
+        assert_eq!("Ｒｕｓｔ．ｒｓ、", punct_style("Ｒｕｓｔ．ｒｓ，", PunctStyle::Touten));
+        assert_eq!("ファイル,ａ．ｔｘｔ", punct_style("ファイル,ａ．ｔｘｔ", PunctStyle::Half));
+    }
+
+    #[test]
+    fn dash_t1() {
+        let h = DigitDash::Hyphen;
+        assert_eq!("スーパー ｽｰﾊﾟｰ", normalize_dashes("ス‐パ─ ｽ−ﾊﾟ－", h));
+        assert_eq!("090-1234-5678 ABC-12", normalize_dashes("090—1234ー5678 ABC‑12", h));
+        assert_eq!("5−3 −1", normalize_dashes("5-3 ‒1", DigitDash::Minus));
+        assert_eq!("らーめん — 漢字—", normalize_dashes("らーめん — 漢字—", h));
+        assert_eq!("ロット-5 ガス-A ｶﾞｽ-A コピー", normalize_dashes("ロット-5 ガス-A ｶﾞｽ-A コピ-", h));
+        assert_eq!("ロット−５", normalize_dashes("ロット—５", DigitDash::Minus));
+        assert_eq!("コピー2部 ツアー2026 ｺﾋﾟｰ2部 メーカーA社",
+                   normalize_dashes("コピー2部 ツアー2026 ｺﾋﾟｰ2部 メーカーA社", h));
+    }
+
     #[test]
     fn kana_t1() {
         assert_eq!(Some(&'\u{30A2}'), HALVES.get(&'\u{FF71}'));
@@ -436,4 +1416,31 @@ mod tests {
         assert_eq!("ガナ", half2kana("ｶﾞﾅ"));
         assert_eq!("がな", combine("か゛な"));
     }
+
+    #[test]
+    fn kana_t2() {
+        assert_eq!("ｶﾞﾅ", kata2half("ガナ"));
+        assert_eq!("ｶﾞﾅ", kata2half("カ ゙ナ"));
+        assert_eq!("ﾜﾞｦﾞﾊﾟ", kata2half("ヷヺパ"));
+        assert_eq!("ﾜｲｴｲﾞ", kata2half("ヮヰヱヸ"));
+        assert_eq!("ｶﾞﾅ", hira2half("がな"));
+        assert_eq!("ﾏﾂｵ ﾊﾞｼｮｳ ｱﾟ", kata2half(&half2kana("ﾏﾂｵ ﾊﾞｼｮｳ ｱﾟ")));
+    }
+
+    #[test]
+    fn vsmark_t1() {
+        assert_eq!("か ゙は ゚", decompose("がぱ", MarkStyle::Combi));
+        assert_eq!("ゝ゛ウ゛", decompose("ゞヴ", MarkStyle::Full));
+        assert_eq!("がぱ", combine(&decompose("がぱ", MarkStyle::Half)));
+        assert_eq!("ひﾟ", decompose("ひ ゚", MarkStyle::Half));
+    }
+
+    #[test]
+    fn vsmark_t2() {
+        assert_eq!("ひ\u{3099}", vsmark2nospace("ひ ゙"));
+        assert_eq!("ひ\u{3099}", vsmark2nospace("ひ゛"));
+        assert_eq!("ア\u{3099}ガ", half2kana_with("ｱﾞｶﾞ", MarkStyle::NoSpace));
+        assert_eq!(half2kana("ｱﾞｶﾞ"), half2kana_with("ｱﾞｶﾞ", MarkStyle::Combi));
+        assert_eq!("あ\u{3099}が", combine_with("あ ゙か゛", MarkStyle::NoSpace));
+    }
 }
@@ -6,8 +6,9 @@ extern crate kana;
 use std::error::Error;
 use std::{io, process, fs};
 use std::io::prelude::*;
-use clap::ArgMatches;
+use clap::{Arg, ArgMatches};
 use kana::*;
+use kana::encoding::{Encoding, ErrorMode};
 
 macro_rules! err { ($e:expr) => ( {
     writeln!(&mut io::stderr(), "{}", $e).unwrap();
@@ -16,7 +17,12 @@ macro_rules! err { ($e:expr) => ( {
 
 fn main() {
     let _args = load_yaml!("cli.yml");
-    let args  = clap::App::from_yaml(_args).get_matches();
+    let args  = clap::App::from_yaml(_args)
+        .arg(classes_arg("wide2ascii", "ascii2wide",
+            "Convert Wide-alphanumeric into normal ASCII"))
+        .arg(classes_arg("ascii2wide", "wide2ascii",
+            "Convert normal ASCII characters into Wide-alphanumeric"))
+        .get_matches();
 
     match main_body(&args, get_input_clap(&args)) {
         Ok(_)  => {},
@@ -24,30 +30,150 @@ fn main() {
     }
 }
 
-fn main_body(args: &ArgMatches, input: Box<dyn BufRead>)
+fn main_body(args: &ArgMatches, mut input: Box<dyn BufRead>)
     -> Result<(), Box<dyn Error>>
 {
+    if let Some(enc) = args.value_of("from").and_then(get_encoding) {
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+        let text = encoding::decode(&bytes, enc, ErrorMode::Strict)?;
+        input = Box::new(io::Cursor::new(text));
+    }
+    let to = args.value_of("to").and_then(get_encoding);
+    let stdout = io::stdout();
+    let mut output = stdout.lock();
+    let wide2ascii_classes = get_classes(args, "wide2ascii");
+    let ascii2wide_classes = get_classes(args, "ascii2wide");
     for _s in input.lines() {
         let mut s = _s?;
+        if args.is_present("hentaigana2kana") { s = hentaigana2kana(&s); }
+        if args.is_present("expand-squared") {
+            s = expand_squared(&s, Width::Full);
+        }
         if args.is_present("half2full")    { s = half2full(&s); }
         if args.is_present("half2kana")    { s = half2kana(&s); }
         if args.is_present("combine")      { s = combine(&s); }
+        if args.is_present("romaji2hira")  { s = romaji2hira(&s); }
+        if args.is_present("romaji2kata")  { s = romaji2kata(&s); }
+        if args.is_present("expand-choon") { s = expand_choon(&s); }
+        if args.is_present("expand-iteration-marks") {
+            s = expand_iteration_marks(&s);
+        }
         if args.is_present("hira2kata")    { s = hira2kata(&s); }
         if args.is_present("kata2hira")    { s = kata2hira(&s); }
+        if args.is_present("modernize-kana") { s = modernize_kana(&s); }
+        if args.is_present("small2large")  { s = small2large(&s); }
+        if args.is_present("large2small")  { s = large2small(&s); }
+        if args.is_present("kata2half")    { s = kata2half(&s); }
+        if args.is_present("hira2half")    { s = hira2half(&s); }
         if args.is_present("vsmark2half")  { s = vsmark2half(&s); }
         if args.is_present("vsmark2full")  { s = vsmark2full(&s); }
         if args.is_present("vsmark2combi") { s = vsmark2combi(&s); }
-        if args.is_present("ascii2wide")   { s = ascii2wide(&s); }
-        if args.is_present("wide2ascii")   { s = wide2ascii(&s); }
+        if args.is_present("vsmark2nospace") { s = vsmark2nospace(&s); }
+        if args.is_present("ascii2wide")   {
+            s = ascii2wide_with(&s, &ascii2wide_classes);
+        }
+        if args.is_present("wide2ascii")   {
+            s = wide2ascii_with(&s, &wide2ascii_classes);
+        }
+        if args.is_present("to-halfwidth") { s = to_halfwidth(&s); }
+        if args.is_present("to-fullwidth") { s = to_fullwidth(&s); }
+        if args.occurrences_of("normalize-dashes") > 0 {
+            s = normalize_dashes(&s, match args.value_of("normalize-dashes") {
+                Some("minus") => DigitDash::Minus,
+                _             => DigitDash::Hyphen,
+            });
+        }
+        if let Some(style) = args.value_of("punct-style") {
+            s = punct_style(&s, match style {
+                "touten" => PunctStyle::Touten,
+                "comma"  => PunctStyle::Comma,
+                "half"   => PunctStyle::Half,
+                _        => PunctStyle::Ascii,
+            });
+        }
         if args.is_present("nowidespace")  { s = nowidespace(&s); }
         if args.is_present("space2wide")   { s = space2wide(&s); }
         if args.is_present("nowideyen")    { s = nowideyen(&s); }
         if args.is_present("yen2wide")     { s = yen2wide(&s); }
-        println!("{}", s);
+        if args.is_present("expand-ligatures") {
+            let mode = match args.value_of("expand-ligatures") {
+                Some("spelled") => LigatureMode::Spelled,
+                _               => LigatureMode::Abbreviated,
+            };
+            s = expand_ligatures(&s, mode);
+        }
+        if args.is_present("kana2romaji") {
+            let system = match args.value_of("kana2romaji") {
+                Some("passport") => RomajiSystem::Passport,
+                Some("kunrei")   => RomajiSystem::Kunrei,
+                Some("nihon")    => RomajiSystem::Nihon,
+                _                => RomajiSystem::Hepburn,
+            };
+            let long_vowel = match args.value_of("long-vowel") {
+                Some("circumflex") => LongVowel::Circumflex,
+                Some("double")     => LongVowel::Double,
+                Some("omit")       => LongVowel::Omit,
+                Some("oh")         => LongVowel::Oh,
+                _                  => LongVowel::Macron,
+            };
+            s = kana2romaji(&s, RomajiOptions { system, long_vowel });
+        }
+        if args.is_present("jis2ms")       { s = jis2ms(&s); }
+        if args.is_present("ms2jis")       { s = ms2jis(&s); }
+        if args.is_present("zengin") {
+            s = zengin::convert(&s).map_err(|es| es.iter()
+                    .map(|e| e.to_string()).collect::<Vec<_>>().join("\n"))?;
+        }
+        match to {
+            Some(enc) => {
+                output.write_all(&encoding::encode(&s, enc, ErrorMode::Strict)?)?;
+                output.write_all(b"\n")?;
+            },
+            None => writeln!(output, "{}", s)?,
+        }
     }
     Ok(())
 }
 
+//  "--wide2ascii" converts all the classes, and "--wide2ascii=digits,alpha" does a part of them.
+//  These are not in cli.yml because YAML cannot require "=" for an optional value.
+fn classes_arg<'a>(name: &'a str, other: &'a str, help: &'a str) -> Arg<'a, 'a> {
+    Arg::with_name(name).long(name).help(help)
+        .takes_value(true).min_values(0).require_equals(true)
+        .use_delimiter(true).value_name("CLASSES")
+        .possible_values(&["digits", "upper", "lower", "alpha", "symbols", "all"])
+        .conflicts_with(other)
+}
+
+fn get_classes(args: &ArgMatches, name: &str) -> AsciiClasses {
+    match args.values_of(name) {
+        Some(values) if values.len() > 0 =>
+            values.fold(AsciiClasses::default(), |mut c, v| {
+            match v {
+                "digits"  => c.digits  = true,
+                "upper"   => c.upper   = true,
+                "lower"   => c.lower   = true,
+                "alpha"   => { c.upper = true; c.lower = true; },
+                "symbols" => c.symbols = true,
+                _         => c = AsciiClasses::all(),
+            }
+            c
+        }),
+        _ => AsciiClasses::all(),
+    }
+}
+
+//  None for UTF-8, which needs no conversion
+fn get_encoding(label: &str) -> Option<Encoding> {
+    match label {
+        "shift_jis" => Some(Encoding::ShiftJis),
+        "cp932"     => Some(Encoding::Cp932),
+        "euc-jp"    => Some(Encoding::EucJp),
+        _           => None,
+    }
+}
+
 fn get_input_clap(args: &ArgMatches) -> Box<dyn BufRead> {
     if args.is_present("INPUT") {
         let f = fs::File::open(args.value_of("INPUT").unwrap())
@@ -0,0 +1,128 @@
+//! Morae[拍] of kana text, for counting and timing syllables
+
+use {is_hiragana, is_katakana, is_halfwidth_kana, is_voiced_mark, CH_VOICED_COMBI, CH_SEMIVOICED_COMBI};
+
+//  Small kana which make contracted sounds with the preceding kana
+const CONTRACTIONS: &str = "ゃゅょぁぃぅぇぉゎャュョァィゥェォヮｬｭｮｧｨｩｪｫ";
+const SOKUONS:      &str = "っッｯ";
+const HATSUONS:     &str = "んンﾝ";
+const CHOONS:       &str = "ーｰ";
+
+/// Kinds of morae yielded by morae()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoraKind {
+    /// Kana standing alone  [か, ガ, ｶﾞ]
+    Regular,
+    /// Kana with a small one making a contracted sound  [きゃ, ファ, ｷｬ]
+    Yoon,
+    /// Geminate consonant  [っ, ッ, ｯ]
+    Sokuon,
+    /// Syllabic nasal  [ん, ン, ﾝ]
+    Hatsuon,
+    /// Prolonged sound  [ー, ｰ]
+    Choon,
+    /// Any other character, such as Kanji, ASCII and spaces
+    Other,
+}
+
+/// A mora of kana text, as a slice of the original string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mora<'a> {
+    /// Characters of the mora, including its small kana and Voiced-sound-marks
+    pub text: &'a str,
+    /// Kind of the mora
+    pub kind: MoraKind,
+}
+
+/// Iterator returned by morae()
+#[derive(Debug, Clone)]
+pub struct Morae<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+fn is_kana(c: char) -> bool {
+    (is_hiragana(c) || is_katakana(c) || is_halfwidth_kana(c)) && !is_voiced_mark(c)
+}
+
+impl<'a> Morae<'a> {
+    //  Length of a Voiced-sound-mark at the position, including a preceding space of the combining style
+    fn mark_len(&self, pos: usize) -> usize {
+        let mut cs = self.s[pos..].chars();
+        match (cs.next(), cs.next()) {
+            (Some(c), _) if is_voiced_mark(c) => c.len_utf8(),
+            (Some(' '), Some(m)) if m == CH_VOICED_COMBI || m == CH_SEMIVOICED_COMBI => 1 + m.len_utf8(),
+            _ => 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Morae<'a> {
+    type Item = Mora<'a>;
+
+    fn next(&mut self) -> Option<Mora<'a>> {
+        let start = self.pos;
+        let c = self.s[start..].chars().next()?;
+        let mut end = start + c.len_utf8();
+        let mut kind = match c {
+            _ if !is_kana(c)            => MoraKind::Other,
+            _ if SOKUONS.contains(c)    => MoraKind::Sokuon,
+            _ if HATSUONS.contains(c)   => MoraKind::Hatsuon,
+            _ if CHOONS.contains(c)     => MoraKind::Choon,
+            _                           => MoraKind::Regular,
+        };
+        if kind != MoraKind::Other {
+            loop {
+                let n = self.mark_len(end);
+                if n > 0 {
+                    end += n;
+                    continue;
+                }
+                match self.s[end..].chars().next() {
+                    Some(x) if kind == MoraKind::Regular && CONTRACTIONS.contains(x) => {
+                        kind = MoraKind::Yoon;
+                        end += x.len_utf8();
+                    },
+                    _ => break,
+                }
+            }
+        }
+        self.pos = end;
+        Some(Mora { text: &self.s[start..end], kind })
+    }
+}
+
+/// Split kana text into morae  [きゃっと -> きゃ, っ, と]
+///
+/// Small kana of contracted sounds and Voiced-sound-marks in any style
+/// are put together with the preceding kana, as combine() does.
+/// Half-width-kana are split in the same way, and each of the other characters makes a mora of its own.
+/// # Examples
+/// ```
+/// use kana::MoraKind;
+/// let ms: Vec<_> = kana::morae("ｷｬｯﾄﾌｰﾄﾞ").map(|m| m.text).collect();
+/// assert_eq!(vec!["ｷｬ", "ｯ", "ﾄ", "ﾌ", "ｰ", "ﾄﾞ"], ms);
+/// let ks: Vec<_> = kana::morae("しゃしん").map(|m| m.kind).collect();
+/// assert_eq!(vec![MoraKind::Yoon, MoraKind::Regular, MoraKind::Hatsuon], ks);
+/// ```
+pub fn morae(s: &str) -> Morae<'_> {
+    Morae { s, pos: 0 }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mora_t1() {
+        let text = |s| morae(s).map(|m| m.text).collect::<Vec<_>>();
+        let kind = |s| morae(s).map(|m| m.kind).collect::<Vec<_>>();
+        assert_eq!(17, morae("ふるいけや かわずとびこむ みずのおと").filter(|m| m.kind != MoraKind::Other).count());
+        assert_eq!(vec!["ファ", "イ", "ル"], text("ファイル"));
+        assert_eq!(vec!["か\u{3099}", "ぱ \u{309A}", "ひ\u{309B}ゃ"], text("か\u{3099}ぱ \u{309A}ひ\u{309B}ゃ"));
+        assert_eq!(vec!["ぁ", "本", " ", "ゝ"], text("ぁ本 ゝ"));
+        assert_eq!(vec![MoraKind::Regular, MoraKind::Choon, MoraKind::Sokuon, MoraKind::Other],
+                   kind("カーッ!"));
+    }
+}
@@ -0,0 +1,400 @@
+//! Romaji[ローマ字] in Hepburn, Kunrei-shiki, Nihon-shiki and IME spellings, to and from kana
+
+use std::collections::HashMap;
+use {wide2ascii, hira2kata, kata2hira, half2kana, combine};
+
+lazy_static! {
+    //  Syllables of all the systems in lower case, and their Hiragana.
+    //  "n" alone, doubled consonants and "-" are handled in romaji2hira().
+    static ref ROMAJI: HashMap<&'static str,&'static str> = [
+        ("a", "あ"), ("i", "い"), ("u", "う"), ("e", "え"), ("o", "お"),
+        ("ka", "か"), ("ki", "き"), ("ku", "く"), ("ke", "け"), ("ko", "こ"),
+        ("kya", "きゃ"), ("kyu", "きゅ"), ("kyo", "きょ"),
+        ("kwa", "くゎ"),
+        ("ca", "か"), ("cu", "く"), ("co", "こ"), ("qa", "くぁ"),
+        ("sa", "さ"), ("si", "し"), ("shi", "し"), ("su", "す"), ("se", "せ"), ("so", "そ"),
+        ("sya", "しゃ"), ("syu", "しゅ"), ("syo", "しょ"),
+        ("sha", "しゃ"), ("shu", "しゅ"), ("sho", "しょ"), ("she", "しぇ"),
+        ("ta", "た"), ("ti", "ち"), ("chi", "ち"), ("tu", "つ"), ("tsu", "つ"),
+        ("te", "て"), ("to", "と"),
+        ("tya", "ちゃ"), ("tyu", "ちゅ"), ("tyo", "ちょ"),
+        ("cha", "ちゃ"), ("chu", "ちゅ"), ("cho", "ちょ"), ("che", "ちぇ"),
+        ("cya", "ちゃ"), ("cyu", "ちゅ"), ("cyo", "ちょ"),
+        ("tsa", "つぁ"), ("thi", "てぃ"), ("twu", "とぅ"),
+        ("na", "な"), ("ni", "に"), ("nu", "ぬ"), ("ne", "ね"), ("no", "の"),
+        ("nya", "にゃ"), ("nyu", "にゅ"), ("nyo", "にょ"),
+        ("ha", "は"), ("hi", "ひ"), ("hu", "ふ"), ("fu", "ふ"), ("he", "へ"), ("ho", "ほ"),
+        ("hya", "ひゃ"), ("hyu", "ひゅ"), ("hyo", "ひょ"),
+        ("fa", "ふぁ"), ("fi", "ふぃ"), ("fe", "ふぇ"), ("fo", "ふぉ"),
+        ("ma", "ま"), ("mi", "み"), ("mu", "む"), ("me", "め"), ("mo", "も"),
+        ("mya", "みゃ"), ("myu", "みゅ"), ("myo", "みょ"),
+        ("ya", "や"), ("yu", "ゆ"), ("ye", "いぇ"), ("yo", "よ"),
+        ("ra", "ら"), ("ri", "り"), ("ru", "る"), ("re", "れ"), ("ro", "ろ"),
+        ("rya", "りゃ"), ("ryu", "りゅ"), ("ryo", "りょ"),
+        ("wa", "わ"), ("wi", "ゐ"), ("we", "ゑ"), ("wo", "を"),
+        ("whi", "うぃ"), ("whe", "うぇ"),
+        ("ga", "が"), ("gi", "ぎ"), ("gu", "ぐ"), ("ge", "げ"), ("go", "ご"),
+        ("gya", "ぎゃ"), ("gyu", "ぎゅ"), ("gyo", "ぎょ"),
+        ("gwa", "ぐゎ"),
+        ("za", "ざ"), ("zi", "じ"), ("ji", "じ"), ("zu", "ず"), ("ze", "ぜ"), ("zo", "ぞ"),
+        ("zya", "じゃ"), ("zyu", "じゅ"), ("zyo", "じょ"),
+        ("ja", "じゃ"), ("ju", "じゅ"), ("jo", "じょ"), ("je", "じぇ"),
+        ("jya", "じゃ"), ("jyu", "じゅ"), ("jyo", "じょ"),
+        ("da", "だ"), ("di", "ぢ"), ("du", "づ"), ("de", "で"), ("do", "ど"),
+        ("dya", "ぢゃ"), ("dyu", "ぢゅ"), ("dyo", "ぢょ"),
+        ("dhi", "でぃ"), ("dwu", "どぅ"),
+        ("ba", "ば"), ("bi", "び"), ("bu", "ぶ"), ("be", "べ"), ("bo", "ぼ"),
+        ("bya", "びゃ"), ("byu", "びゅ"), ("byo", "びょ"),
+        ("pa", "ぱ"), ("pi", "ぴ"), ("pu", "ぷ"), ("pe", "ぺ"), ("po", "ぽ"),
+        ("pya", "ぴゃ"), ("pyu", "ぴゅ"), ("pyo", "ぴょ"),
+        ("va", "ゔぁ"), ("vi", "ゔぃ"), ("vu", "ゔ"), ("ve", "ゔぇ"), ("vo", "ゔぉ"),
+        ("xa", "ぁ"), ("xi", "ぃ"), ("xu", "ぅ"), ("xe", "ぇ"), ("xo", "ぉ"),
+        ("la", "ぁ"), ("li", "ぃ"), ("lu", "ぅ"), ("le", "ぇ"), ("lo", "ぉ"),
+        ("xya", "ゃ"), ("xyu", "ゅ"), ("xyo", "ょ"),
+        ("lya", "ゃ"), ("lyu", "ゅ"), ("lyo", "ょ"),
+        ("xtu", "っ"), ("ltu", "っ"), ("xtsu", "っ"), ("ltsu", "っ"),
+        ("xwa", "ゎ"), ("lwa", "ゎ"), ("xka", "ゕ"), ("lka", "ゕ"), ("xke", "ゖ"), ("lke", "ゖ"),
+        ("xn", "ん"),
+    ].iter().copied().collect();
+
+    //  Hiragana, and ヷヸヹヺ which have no Hiragana, with their Romaji in Hepburn,
+    //  Kunrei-shiki and Nihon-shiki. Yoon, っ, ん and ー are handled in kana2romaji().
+    static ref KANA_ROMAJI: HashMap<char,[&'static str; 3]> = [
+        ('あ', ["a", "a", "a"]), ('い', ["i", "i", "i"]), ('う', ["u", "u", "u"]),
+        ('え', ["e", "e", "e"]), ('お', ["o", "o", "o"]),
+        ('か', ["ka", "ka", "ka"]), ('き', ["ki", "ki", "ki"]), ('く', ["ku", "ku", "ku"]),
+        ('け', ["ke", "ke", "ke"]), ('こ', ["ko", "ko", "ko"]),
+        ('が', ["ga", "ga", "ga"]), ('ぎ', ["gi", "gi", "gi"]), ('ぐ', ["gu", "gu", "gu"]),
+        ('げ', ["ge", "ge", "ge"]), ('ご', ["go", "go", "go"]),
+        ('さ', ["sa", "sa", "sa"]), ('し', ["shi", "si", "si"]), ('す', ["su", "su", "su"]),
+        ('せ', ["se", "se", "se"]), ('そ', ["so", "so", "so"]),
+        ('ざ', ["za", "za", "za"]), ('じ', ["ji", "zi", "zi"]), ('ず', ["zu", "zu", "zu"]),
+        ('ぜ', ["ze", "ze", "ze"]), ('ぞ', ["zo", "zo", "zo"]),
+        ('た', ["ta", "ta", "ta"]), ('ち', ["chi", "ti", "ti"]), ('つ', ["tsu", "tu", "tu"]),
+        ('て', ["te", "te", "te"]), ('と', ["to", "to", "to"]),
+        ('だ', ["da", "da", "da"]), ('ぢ', ["ji", "zi", "di"]), ('づ', ["zu", "zu", "du"]),
+        ('で', ["de", "de", "de"]), ('ど', ["do", "do", "do"]),
+        ('な', ["na", "na", "na"]), ('に', ["ni", "ni", "ni"]), ('ぬ', ["nu", "nu", "nu"]),
+        ('ね', ["ne", "ne", "ne"]), ('の', ["no", "no", "no"]),
+        ('は', ["ha", "ha", "ha"]), ('ひ', ["hi", "hi", "hi"]), ('ふ', ["fu", "hu", "hu"]),
+        ('へ', ["he", "he", "he"]), ('ほ', ["ho", "ho", "ho"]),
+        ('ば', ["ba", "ba", "ba"]), ('び', ["bi", "bi", "bi"]), ('ぶ', ["bu", "bu", "bu"]),
+        ('べ', ["be", "be", "be"]), ('ぼ', ["bo", "bo", "bo"]),
+        ('ぱ', ["pa", "pa", "pa"]), ('ぴ', ["pi", "pi", "pi"]), ('ぷ', ["pu", "pu", "pu"]),
+        ('ぺ', ["pe", "pe", "pe"]), ('ぽ', ["po", "po", "po"]),
+        ('ま', ["ma", "ma", "ma"]), ('み', ["mi", "mi", "mi"]), ('む', ["mu", "mu", "mu"]),
+        ('め', ["me", "me", "me"]), ('も', ["mo", "mo", "mo"]),
+        ('や', ["ya", "ya", "ya"]), ('ゆ', ["yu", "yu", "yu"]), ('よ', ["yo", "yo", "yo"]),
+        ('ら', ["ra", "ra", "ra"]), ('り', ["ri", "ri", "ri"]), ('る', ["ru", "ru", "ru"]),
+        ('れ', ["re", "re", "re"]), ('ろ', ["ro", "ro", "ro"]),
+        ('わ', ["wa", "wa", "wa"]), ('ゐ', ["i", "i", "wi"]), ('ゑ', ["e", "e", "we"]),
+        ('を', ["o", "o", "wo"]), ('ゔ', ["vu", "vu", "vu"]),
+        ('ぁ', ["a", "a", "a"]), ('ぃ', ["i", "i", "i"]), ('ぅ', ["u", "u", "u"]),
+        ('ぇ', ["e", "e", "e"]), ('ぉ', ["o", "o", "o"]),
+        ('ゃ', ["ya", "ya", "ya"]), ('ゅ', ["yu", "yu", "yu"]), ('ょ', ["yo", "yo", "yo"]),
+        ('ゎ', ["wa", "wa", "wa"]), ('ゕ', ["ka", "ka", "ka"]), ('ゖ', ["ke", "ke", "ke"]),
+        ('ヷ', ["va", "va", "va"]), ('ヸ', ["vi", "vi", "vi"]), ('ヹ', ["ve", "ve", "ve"]),
+        ('ヺ', ["vo", "vo", "vo"]),
+    ].iter().copied().collect();
+}
+
+const MAX_SYLLABLE: usize = 4;
+
+fn is_vowel(c: char) -> bool {
+    "aiueo".contains(c)
+}
+
+//  Long vowels with macron or circumflex, spelled out
+fn unmacron(c: char) -> Vec<char> {
+    match c {
+        'ā' | 'â' => vec!['a', 'a'],
+        'ī' | 'î' => vec!['i', 'i'],
+        'ū' | 'û' => vec!['u', 'u'],
+        'ē' | 'ê' => vec!['e', 'e'],
+        'ō' | 'ô' => vec!['o', 'u'],
+        _ => vec![c],
+    }
+}
+
+/// Convert Romaji into Hiragana  [konnichiha -> こんにちは]
+///
+/// Hepburn, Kunrei-shiki and Nihon-shiki spellings are all accepted, as well as
+/// IME conventions: "nn" and "n'" for ん, "x" and "l" for small kana, "xtu" and
+/// doubled consonants for っ, and "-" after kana for ー. Wide-alphanumerics are read as ASCII.
+/// Long vowels with macron or circumflex are spelled out, [ō] as "おう".
+/// Characters that are not Romaji are left as they are.
+/// # Examples
+/// ```
+/// assert_eq!("こんにちは", kana::romaji2hira("konnichiha"));
+/// assert_eq!("しんぶん きっぷ かんい", kana::romaji2hira("Shimbun kippu kan'i"));
+/// assert_eq!("ちゃー", kana::romaji2hira("ｔｙａ－"));
+/// ```
+pub fn romaji2hira(s: &str) -> String {
+    //  Lowered characters to match, and the source characters to leave unmatched
+    let mut cs = Vec::with_capacity(s.len());
+    let mut origs = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        for (j, l) in wide2ascii(&c.to_string()).to_lowercase().chars().flat_map(unmacron).enumerate() {
+            cs.push(l);
+            origs.push(if j == 0 { c } else { l });
+        }
+    }
+    let mut line = String::with_capacity(s.len() * 3);
+    let mut kana = false;
+    let mut i = 0;
+    'outer: while i < cs.len() {
+        for n in (1..=MAX_SYLLABLE.min(cs.len() - i)).rev() {
+            let k: String = cs[i..i + n].iter().collect();
+            if let Some(h) = ROMAJI.get(k.as_str()) {
+                line.push_str(h);
+                i += n;
+                kana = true;
+                continue 'outer;
+            }
+        }
+        let c = cs[i];
+        let next = cs.get(i + 1).copied();
+        match (c, next) {
+            ('n', Some('\'')) => { line.push('ん'); i += 2; },
+            ('n', Some('n')) => {
+                line.push('ん');
+                let after = cs.get(i + 2).copied();
+                i += if after.is_some_and(|a| is_vowel(a) || a == 'y') { 1 } else { 2 };
+            },
+            ('n', n) if n.is_none_or(|n| !is_vowel(n) && n != 'y') => {
+                line.push('ん');
+                i += 1;
+            },
+            ('m', Some('b')) | ('m', Some('m')) | ('m', Some('p')) => {
+                line.push('ん');
+                i += 1;
+            },
+            ('t', Some('c')) => { line.push('っ'); i += 1; },
+            (c, Some(n)) if c == n && c.is_ascii_lowercase() && !is_vowel(c) => {
+                line.push('っ');
+                i += 1;
+            },
+            ('-', _) if kana => { line.push('ー'); i += 1; },
+            _ => { line.push(origs[i]); i += 1; kana = false; continue; },
+        }
+        kana = true;
+    }
+    line
+}
+
+/// Convert Romaji into Katakana  [konpyu-ta- -> コンピューター]
+///
+/// Same as romaji2hira() followed by hira2kata().
+/// # Examples
+/// ```
+/// assert_eq!("コンピューター", kana::romaji2kata("konpyu-ta-"));
+/// assert_eq!("ｺﾝﾋﾟｭｰﾀｰ", kana::kata2half(&kana::romaji2kata("konpyu-ta-")));
+/// ```
+pub fn romaji2kata(s: &str) -> String {
+    hira2kata(&romaji2hira(s))
+}
+
+/// Romanization systems of kana2romaji()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RomajiSystem {
+    /// Modified Hepburn  [しんぶん -> shinbun]
+    #[default]
+    Hepburn,
+    /// Hepburn of Japanese passports, in upper case  [しんぶん -> SHIMBUN]
+    Passport,
+    /// Kunrei-shiki  [ちず -> tizu]
+    Kunrei,
+    /// Nihon-shiki  [ちぢみ -> tidimi]
+    Nihon,
+}
+
+/// Notations of long vowels on kana2romaji()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LongVowel {
+    /// Vowels with macron  [とうきょう -> tōkyō]
+    #[default]
+    Macron,
+    /// Vowels with circumflex  [とうきょう -> tôkyô]
+    Circumflex,
+    /// Vowels as spelled in kana, and doubled for ー  [とうきょう コーヒー -> toukyou koohii]
+    Double,
+    /// Single vowels  [とうきょう -> tokyo]
+    Omit,
+    /// "oh" for long o, and single vowels for the others  [さとう おおの -> satoh ohno]
+    Oh,
+}
+
+/// Options of kana2romaji()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RomajiOptions {
+    /// Romanization system
+    pub system: RomajiSystem,
+    /// Notation of long vowels, where Macron and Circumflex fall back to Omit on Passport
+    pub long_vowel: LongVowel,
+}
+
+enum Unit {
+    Syllable(String),
+    Sokuon,
+    Hatsuon,
+    Choon,
+    Other(char),
+}
+
+//  Romaji of a kana followed by a small one, such as きゃ, しぇ and ふぁ
+fn contract(base: &str, hepburn: &str, small: char) -> Option<String> {
+    let vowel = match small {
+        'ゃ' => "a", 'ゅ' => "u", 'ょ' => "o", 'ぇ' if base.ends_with('i') => "e",
+        'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' if hepburn != "u" =>
+            return Some(format!("{}{}", &hepburn[..hepburn.len() - 1], KANA_ROMAJI[&small][0])),
+        'ぃ' | 'ぇ' | 'ぉ' => return Some(format!("w{}", KANA_ROMAJI[&small][0])),
+        'ゎ' => return Some(format!("{}wa", &base[..base.len() - 1])),
+        _ => return None,
+    };
+    if !base.ends_with('i') || base == "i" && vowel != "e" { return None; }
+    let stem = &base[..base.len() - 1];
+    if stem.ends_with('h') || stem == "j" {
+        Some(format!("{}{}", stem, vowel))
+    } else {
+        Some(format!("{}y{}", stem, vowel))
+    }
+}
+
+fn long_vowel(r: &str, spelled: &str, notation: LongVowel) -> String {
+    let (stem, v) = r.split_at(r.len() - 1);
+    let long = match (notation, v) {
+        (LongVowel::Macron, _) => ["ā", "ī", "ū", "ē", "ō"]["aiueo".find(v).unwrap()],
+        (LongVowel::Circumflex, _) => ["â", "î", "û", "ê", "ô"]["aiueo".find(v).unwrap()],
+        (LongVowel::Double, _) => return format!("{}{}", r, spelled),
+        (LongVowel::Oh, "o") => "oh",
+        (LongVowel::Oh, _) | (LongVowel::Omit, _) => v,
+    };
+    format!("{}{}", stem, long)
+}
+
+/// Convert kana into Romaji  [とうきょう -> tōkyō]
+///
+/// Hiragana, Katakana and Half-width-kana are accepted, through half2kana(), combine() and kata2hira().
+/// Long vowels are found in ー, ああ, うう, ええ, おう and おお, but not in いい and えい.
+/// っ doubles the next consonant, or becomes an apostrophe where no consonant follows.
+/// Characters that are not kana are left as they are.
+/// # Examples
+/// ```
+/// use kana::{RomajiOptions, RomajiSystem, LongVowel};
+/// assert_eq!("tōkyō shinbun", kana::kana2romaji("とうきょう シンブン", RomajiOptions::default()));
+/// let passport = RomajiOptions { system: RomajiSystem::Passport, long_vowel: LongVowel::Oh };
+/// assert_eq!("OHNO SATOH", kana::kana2romaji("オオノ ｻﾄｳ", passport));
+/// let kunrei = RomajiOptions { system: RomajiSystem::Kunrei, long_vowel: LongVowel::Circumflex };
+/// assert_eq!("tyûsya", kana::kana2romaji("ちゅうしゃ", kunrei));
+/// ```
+pub fn kana2romaji(s: &str, opts: RomajiOptions) -> String {
+    let sys = match opts.system {
+        RomajiSystem::Hepburn | RomajiSystem::Passport => 0,
+        RomajiSystem::Kunrei => 1,
+        RomajiSystem::Nihon => 2,
+    };
+    let passport = opts.system == RomajiSystem::Passport;
+    let notation = match opts.long_vowel {
+        LongVowel::Macron | LongVowel::Circumflex if passport => LongVowel::Omit,
+        x => x,
+    };
+
+    let cs: Vec<char> = kata2hira(&combine(&half2kana(s))).chars().collect();
+    let mut units = Vec::with_capacity(cs.len());
+    let mut i = 0;
+    while i < cs.len() {
+        let c = cs[i];
+        i += 1;
+        units.push(match (c, KANA_ROMAJI.get(&c)) {
+            ('っ', _) => Unit::Sokuon,
+            ('ん', _) => Unit::Hatsuon,
+            ('ー', _) => Unit::Choon,
+            (_, Some(r)) => {
+                match cs.get(i).and_then(|&n| contract(r[sys], r[0], n)) {
+                    Some(x) => { i += 1; Unit::Syllable(x) },
+                    None => Unit::Syllable(r[sys].to_string()),
+                }
+            },
+            (_, None) => Unit::Other(c),
+        });
+    }
+
+    let mut line = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < units.len() {
+        match &units[i] {
+            Unit::Syllable(r) => {
+                let v = &r[r.len() - 1..];
+                let long = match units.get(i + 1) {
+                    Some(Unit::Choon) => Some(v),
+                    Some(Unit::Syllable(n)) if n.len() == 1 && "aiueo".contains(v) &&
+                        (n == v && v != "i" || v == "o" && n == "u") => Some(n.as_str()),
+                    _ => None,
+                };
+                match long {
+                    Some(spelled) => {
+                        line.push_str(&long_vowel(r, spelled, notation));
+                        i += 1;
+                    },
+                    None => line.push_str(r),
+                }
+            },
+            Unit::Sokuon => match units.get(i + 1) {
+                Some(Unit::Syllable(n)) if n.starts_with("ch") => line.push('t'),
+                Some(Unit::Syllable(n)) if !"aiueo".contains(&n[..1]) => line.push_str(&n[..1]),
+                _ => line.push('\''),
+            },
+            Unit::Hatsuon => match units.get(i + 1) {
+                Some(Unit::Syllable(n)) if passport && "bmp".contains(&n[..1]) => line.push('m'),
+                Some(Unit::Syllable(n)) if !passport && "aiueoy".contains(&n[..1]) => line.push_str("n'"),
+                _ => line.push('n'),
+            },
+            Unit::Choon => line.push('-'),
+            Unit::Other(c) => line.push(*c),
+        }
+        i += 1;
+    }
+    if passport { line.to_uppercase() } else { line }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn romaji_t1() {
+        assert!(ROMAJI.keys().all(|k| k.chars().count() <= MAX_SYLLABLE));
+        assert_eq!("ふじさん つくえ ちず", romaji2hira("Fujisan tukue tizu"));
+        assert_eq!("しゃしん じゃま ぢゃ", romaji2hira("syasin zyama dya"));
+        assert_eq!("こんにちは こんにちは", romaji2hira("konnnitiha konnichiha"));
+        assert_eq!("まっちゃ がっこう ぁっ", romaji2hira("matcha gakkou xaltu"));
+        assert_eq!("とうきょう とうきょう", romaji2hira("Tōkyō TÔKYÔ"));
+        assert_eq!("ほん 本", romaji2hira("hon 本"));
+        assert_eq!("ヴァイオリン", romaji2kata("vaiorin"));
+        assert_eq!("ＪＲ あBC ＃1", romaji2hira("ＪＲ ABC ＃1"));
+        assert_eq!("2020-01-01 03-1234 -- らーめんー", romaji2hira("2020-01-01 03-1234 -- ra-men-"));
+    }
+
+    #[test]
+    fn romaji_t2() {
+        let opts = |system, long_vowel| RomajiOptions { system, long_vowel };
+        let hep = RomajiOptions::default();
+        assert_eq!("kippu matcha kan'i gakkō", kana2romaji("きっぷ まっちゃ かんい ｶﾞｯｺｳ", hep));
+        assert_eq!("kōhī fairu tī shatsu", kana2romaji("コーヒー ファイル ティー シャツ", hep));
+        assert_eq!("oniisan nēsan sensei", kana2romaji("おにいさん ねえさん せんせい", hep));
+        assert_eq!("ji zu ja wi kwa", kana2romaji("ぢ づ ぢゃ ウィ くゎ", hep));
+        let double = opts(RomajiSystem::Hepburn, LongVowel::Double);
+        assert_eq!("toukyou koohii", kana2romaji("とうきょう コーヒー", double));
+        let omit = opts(RomajiSystem::Passport, LongVowel::Macron);
+        assert_eq!("SHIMBUN KANI TOKYO", kana2romaji("しんぶん かんい とうきょう", omit));
+        let kunrei = opts(RomajiSystem::Kunrei, LongVowel::Circumflex);
+        assert_eq!("syasin tizu zya hukuoka", kana2romaji("しゃしん ちず ぢゃ ふくおか", kunrei));
+        let nihon = opts(RomajiSystem::Nihon, LongVowel::Omit);
+        assert_eq!("tidimi tuduki dya wo", kana2romaji("ちぢみ つづき ぢゃ を", nihon));
+        assert_eq!("漢字 ABC", kana2romaji("漢字 ABC", hep));
+        assert_eq!("a'! che' ' itte", kana2romaji("あっ! ちぇっ っ いって", hep));
+        assert_eq!("va vi ve vo ye", kana2romaji("ヷ ヸ ヹ ヺ いぇ", hep));
+        assert_eq!("A'", kana2romaji("アッ", opts(RomajiSystem::Passport, LongVowel::Omit)));
+    }
+}
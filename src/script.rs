@@ -0,0 +1,174 @@
+//! Classification of characters into Japanese scripts, and runs of them
+
+use std::ops::Range;
+use {CH_VOICED_COMBI, CH_SEMIVOICED_COMBI, CH_VOICED_FULL, CH_SEMIVOICED_FULL,
+     CH_VOICED_HALF, CH_SEMIVOICED_HALF};
+
+/// Scripts which script_runs() tells apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// Hiragana, Hentaigana and their iteration marks  [あ, ゝ]
+    Hiragana,
+    /// Katakana, including the small ones for Ainu  [ア, ヽ, ㇰ]
+    Katakana,
+    /// Half-width-kana and their marks  [ｱ, ﾞ]
+    HalfwidthKana,
+    /// CJK ideographs and ideographic iteration marks  [漢, 々]
+    Kanji,
+    /// Wide-alphanumerics and symbols  [Ａ, ！]
+    FullwidthAscii,
+    /// ASCII  [A, !]
+    Ascii,
+    /// Any other character, such as Japanese punctuation
+    Other,
+}
+
+impl Script {
+    /// Script of a character
+    ///
+    /// Voiced-sound-marks are classified as Hiragana, or as HalfwidthKana for ﾞﾟ,
+    /// while script_runs() puts them into the run of the preceding character.
+    /// # Examples
+    /// ```
+    /// use kana::Script;
+    /// assert_eq!(Script::Katakana, Script::of('ア'));
+    /// assert_eq!(Script::Kanji, Script::of('々'));
+    /// ```
+    pub fn of(c: char) -> Script {
+        if is_halfwidth_kana(c) {
+            Script::HalfwidthKana
+        } else if is_hiragana(c) || is_voiced_mark(c) {
+            Script::Hiragana
+        } else if is_katakana(c) {
+            Script::Katakana
+        } else if is_kanji(c) {
+            Script::Kanji
+        } else if is_fullwidth_ascii(c) {
+            Script::FullwidthAscii
+        } else if c.is_ascii() {
+            Script::Ascii
+        } else {
+            Script::Other
+        }
+    }
+}
+
+/// Whether a character is Hiragana  [あ, ゟ, 𛀂]
+/// # Examples
+/// ```
+/// assert!(kana::is_hiragana('ゝ') && !kana::is_hiragana('ア'));
+/// ```
+pub fn is_hiragana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{3096}' | '\u{309D}'..='\u{309F}' |
+                '\u{1B001}'..='\u{1B11F}' | '\u{1B132}' | '\u{1B150}'..='\u{1B152}')
+}
+
+/// Whether a character is Katakana, including Prolonged-sound-mark  [ア, ー, ㇰ]
+/// # Examples
+/// ```
+/// assert!(kana::is_katakana('ー') && !kana::is_katakana('ｱ'));
+/// ```
+pub fn is_katakana(c: char) -> bool {
+    matches!(c, '\u{30A1}'..='\u{30FA}' | '\u{30FC}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' |
+                '\u{1B000}' | '\u{1B120}'..='\u{1B122}' | '\u{1B155}' | '\u{1B164}'..='\u{1B167}')
+}
+
+/// Whether a character is Half-width-kana, including ｰﾞﾟ  [ｱ, ﾞ]
+/// # Examples
+/// ```
+/// assert!(kana::is_halfwidth_kana('ﾟ') && !kana::is_halfwidth_kana('｡'));
+/// ```
+pub fn is_halfwidth_kana(c: char) -> bool {
+    ('\u{FF66}'..='\u{FF9F}').contains(&c)
+}
+
+/// Whether a character is a CJK ideograph or an ideographic mark  [漢, 々, 〇]
+/// # Examples
+/// ```
+/// assert!(kana::is_kanji('漢') && !kana::is_kanji('ヶ'));
+/// ```
+pub fn is_kanji(c: char) -> bool {
+    matches!(c, '\u{3005}' | '\u{3007}' | '\u{3021}'..='\u{3029}' | '\u{3038}'..='\u{303B}' |
+                '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' |
+                '\u{20000}'..='\u{323AF}')
+}
+
+/// Whether a character is a Wide-alphanumeric or a wide ASCII symbol  [Ａ, ！]
+/// # Examples
+/// ```
+/// assert!(kana::is_fullwidth_ascii('Ａ') && !kana::is_fullwidth_ascii('\u{3000}'));
+/// ```
+pub fn is_fullwidth_ascii(c: char) -> bool {
+    ('\u{FF01}'..='\u{FF5E}').contains(&c)
+}
+
+/// Whether a character is a Voiced- or Semi-voiced-sound-mark in any style  [゙, ゛, ﾞ]
+/// # Examples
+/// ```
+/// assert!(kana::is_voiced_mark('\u{3099}') && kana::is_voiced_mark('ﾟ'));
+/// ```
+pub fn is_voiced_mark(c: char) -> bool {
+    matches!(c, CH_VOICED_COMBI | CH_SEMIVOICED_COMBI |
+                CH_VOICED_FULL  | CH_SEMIVOICED_FULL  |
+                CH_VOICED_HALF  | CH_SEMIVOICED_HALF)
+}
+
+/// Iterator returned by script_runs()
+#[derive(Debug, Clone)]
+pub struct ScriptRuns<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for ScriptRuns<'a> {
+    type Item = (Script, Range<usize>);
+
+    fn next(&mut self) -> Option<(Script, Range<usize>)> {
+        let start = self.pos;
+        let mut cs = self.s[start..].chars();
+        let first = cs.next()?;
+        let script = Script::of(first);
+        let kana = matches!(script, Script::Hiragana | Script::Katakana);
+        self.pos += first.len_utf8();
+        for c in cs {
+            if Script::of(c) != script && !(kana && (is_voiced_mark(c) || c == 'ー')) { break; }
+            self.pos += c.len_utf8();
+        }
+        Some((script, start..self.pos))
+    }
+}
+
+/// Split a string into runs of one script, with their byte ranges  [ひらがなカナ漢字 -> ひらがな, カナ, 漢字]
+///
+/// Voiced-sound-marks and Prolonged-sound-marks join the run of the preceding kana,
+/// so that "カ゛" and "らーめん" make one run each.
+/// # Examples
+/// ```
+/// use kana::Script;
+/// let s = "らーめんをｶﾞｯﾂリ食べた!";
+/// let runs: Vec<_> = kana::script_runs(s).map(|(k, r)| (k, &s[r])).collect();
+/// assert_eq!(vec![(Script::Hiragana, "らーめんを"), (Script::HalfwidthKana, "ｶﾞｯﾂ"),
+///                 (Script::Katakana, "リ"), (Script::Kanji, "食"),
+///                 (Script::Hiragana, "べた"), (Script::Ascii, "!")], runs);
+/// ```
+pub fn script_runs(s: &str) -> ScriptRuns<'_> {
+    ScriptRuns { s, pos: 0 }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_t1() {
+        let runs = |s| script_runs(s).map(|(k, r)| (k, r.len())).collect::<Vec<_>>();
+        assert_eq!(vec![(Script::Katakana, 9), (Script::Kanji, 6)], runs("ガ\u{3099}ー漢字"));
+        assert_eq!(vec![(Script::FullwidthAscii, 6), (Script::Ascii, 2), (Script::Other, 3)],
+                   runs("ＡＢ12。"));
+        assert_eq!(Vec::<(Script, usize)>::new(), runs(""));
+        assert_eq!(Script::Hiragana, Script::of('\u{1B002}'));
+        assert_eq!(Script::Katakana, Script::of('ㇷ'));
+        assert_eq!(Script::Other, Script::of('・'));
+    }
+}
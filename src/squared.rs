@@ -0,0 +1,179 @@
+//! Squared[㌔] and circled[㋐] Katakana in CJK Compatibility and Enclosed CJK
+
+use std::collections::HashMap;
+use {kata2half, Width};
+
+lazy_static! {
+    static ref SQUARES: HashMap<char,&'static str> = [
+        ('\u{32D0}', "\u{30A2}"),                                  //  ㋐	32D0	ア
+        ('\u{32D1}', "\u{30A4}"),                                  //  ㋑	32D1	イ
+        ('\u{32D2}', "\u{30A6}"),                                  //  ㋒	32D2	ウ
+        ('\u{32D3}', "\u{30A8}"),                                  //  ㋓	32D3	エ
+        ('\u{32D4}', "\u{30AA}"),                                  //  ㋔	32D4	オ
+        ('\u{32D5}', "\u{30AB}"),                                  //  ㋕	32D5	カ
+        ('\u{32D6}', "\u{30AD}"),                                  //  ㋖	32D6	キ
+        ('\u{32D7}', "\u{30AF}"),                                  //  ㋗	32D7	ク
+        ('\u{32D8}', "\u{30B1}"),                                  //  ㋘	32D8	ケ
+        ('\u{32D9}', "\u{30B3}"),                                  //  ㋙	32D9	コ
+        ('\u{32DA}', "\u{30B5}"),                                  //  ㋚	32DA	サ
+        ('\u{32DB}', "\u{30B7}"),                                  //  ㋛	32DB	シ
+        ('\u{32DC}', "\u{30B9}"),                                  //  ㋜	32DC	ス
+        ('\u{32DD}', "\u{30BB}"),                                  //  ㋝	32DD	セ
+        ('\u{32DE}', "\u{30BD}"),                                  //  ㋞	32DE	ソ
+        ('\u{32DF}', "\u{30BF}"),                                  //  ㋟	32DF	タ
+        ('\u{32E0}', "\u{30C1}"),                                  //  ㋠	32E0	チ
+        ('\u{32E1}', "\u{30C4}"),                                  //  ㋡	32E1	ツ
+        ('\u{32E2}', "\u{30C6}"),                                  //  ㋢	32E2	テ
+        ('\u{32E3}', "\u{30C8}"),                                  //  ㋣	32E3	ト
+        ('\u{32E4}', "\u{30CA}"),                                  //  ㋤	32E4	ナ
+        ('\u{32E5}', "\u{30CB}"),                                  //  ㋥	32E5	ニ
+        ('\u{32E6}', "\u{30CC}"),                                  //  ㋦	32E6	ヌ
+        ('\u{32E7}', "\u{30CD}"),                                  //  ㋧	32E7	ネ
+        ('\u{32E8}', "\u{30CE}"),                                  //  ㋨	32E8	ノ
+        ('\u{32E9}', "\u{30CF}"),                                  //  ㋩	32E9	ハ
+        ('\u{32EA}', "\u{30D2}"),                                  //  ㋪	32EA	ヒ
+        ('\u{32EB}', "\u{30D5}"),                                  //  ㋫	32EB	フ
+        ('\u{32EC}', "\u{30D8}"),                                  //  ㋬	32EC	ヘ
+        ('\u{32ED}', "\u{30DB}"),                                  //  ㋭	32ED	ホ
+        ('\u{32EE}', "\u{30DE}"),                                  //  ㋮	32EE	マ
+        ('\u{32EF}', "\u{30DF}"),                                  //  ㋯	32EF	ミ
+        ('\u{32F0}', "\u{30E0}"),                                  //  ㋰	32F0	ム
+        ('\u{32F1}', "\u{30E1}"),                                  //  ㋱	32F1	メ
+        ('\u{32F2}', "\u{30E2}"),                                  //  ㋲	32F2	モ
+        ('\u{32F3}', "\u{30E4}"),                                  //  ㋳	32F3	ヤ
+        ('\u{32F4}', "\u{30E6}"),                                  //  ㋴	32F4	ユ
+        ('\u{32F5}', "\u{30E8}"),                                  //  ㋵	32F5	ヨ
+        ('\u{32F6}', "\u{30E9}"),                                  //  ㋶	32F6	ラ
+        ('\u{32F7}', "\u{30EA}"),                                  //  ㋷	32F7	リ
+        ('\u{32F8}', "\u{30EB}"),                                  //  ㋸	32F8	ル
+        ('\u{32F9}', "\u{30EC}"),                                  //  ㋹	32F9	レ
+        ('\u{32FA}', "\u{30ED}"),                                  //  ㋺	32FA	ロ
+        ('\u{32FB}', "\u{30EF}"),                                  //  ㋻	32FB	ワ
+        ('\u{32FC}', "\u{30F0}"),                                  //  ㋼	32FC	ヰ
+        ('\u{32FD}', "\u{30F1}"),                                  //  ㋽	32FD	ヱ
+        ('\u{32FE}', "\u{30F2}"),                                  //  ㋾	32FE	ヲ
+        ('\u{3300}', "\u{30A2}\u{30D1}\u{30FC}\u{30C8}"),          //  ㌀	3300	アパート
+        ('\u{3301}', "\u{30A2}\u{30EB}\u{30D5}\u{30A1}"),          //  ㌁	3301	アルファ
+        ('\u{3302}', "\u{30A2}\u{30F3}\u{30DA}\u{30A2}"),          //  ㌂	3302	アンペア
+        ('\u{3303}', "\u{30A2}\u{30FC}\u{30EB}"),                  //  ㌃	3303	アール
+        ('\u{3304}', "\u{30A4}\u{30CB}\u{30F3}\u{30B0}"),          //  ㌄	3304	イニング
+        ('\u{3305}', "\u{30A4}\u{30F3}\u{30C1}"),                  //  ㌅	3305	インチ
+        ('\u{3306}', "\u{30A6}\u{30A9}\u{30F3}"),                  //  ㌆	3306	ウォン
+        ('\u{3307}', "\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C9}"),  //  ㌇	3307	エスクード
+        ('\u{3308}', "\u{30A8}\u{30FC}\u{30AB}\u{30FC}"),          //  ㌈	3308	エーカー
+        ('\u{3309}', "\u{30AA}\u{30F3}\u{30B9}"),                  //  ㌉	3309	オンス
+        ('\u{330A}', "\u{30AA}\u{30FC}\u{30E0}"),                  //  ㌊	330A	オーム
+        ('\u{330B}', "\u{30AB}\u{30A4}\u{30EA}"),                  //  ㌋	330B	カイリ
+        ('\u{330C}', "\u{30AB}\u{30E9}\u{30C3}\u{30C8}"),          //  ㌌	330C	カラット
+        ('\u{330D}', "\u{30AB}\u{30ED}\u{30EA}\u{30FC}"),          //  ㌍	330D	カロリー
+        ('\u{330E}', "\u{30AC}\u{30ED}\u{30F3}"),                  //  ㌎	330E	ガロン
+        ('\u{330F}', "\u{30AC}\u{30F3}\u{30DE}"),                  //  ㌏	330F	ガンマ
+        ('\u{3310}', "\u{30AE}\u{30AC}"),                          //  ㌐	3310	ギガ
+        ('\u{3311}', "\u{30AE}\u{30CB}\u{30FC}"),                  //  ㌑	3311	ギニー
+        ('\u{3312}', "\u{30AD}\u{30E5}\u{30EA}\u{30FC}"),          //  ㌒	3312	キュリー
+        ('\u{3313}', "\u{30AE}\u{30EB}\u{30C0}\u{30FC}"),          //  ㌓	3313	ギルダー
+        ('\u{3314}', "\u{30AD}\u{30ED}"),                          //  ㌔	3314	キロ
+        ('\u{3315}', "\u{30AD}\u{30ED}\u{30B0}\u{30E9}\u{30E0}"),  //  ㌕	3315	キログラム
+        ('\u{3316}', "\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}"), //  ㌖	3316	キロメートル
+        ('\u{3317}', "\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}"),  //  ㌗	3317	キロワット
+        ('\u{3318}', "\u{30B0}\u{30E9}\u{30E0}"),                  //  ㌘	3318	グラム
+        ('\u{3319}', "\u{30B0}\u{30E9}\u{30E0}\u{30C8}\u{30F3}"),  //  ㌙	3319	グラムトン
+        ('\u{331A}', "\u{30AF}\u{30EB}\u{30BC}\u{30A4}\u{30ED}"),  //  ㌚	331A	クルゼイロ
+        ('\u{331B}', "\u{30AF}\u{30ED}\u{30FC}\u{30CD}"),          //  ㌛	331B	クローネ
+        ('\u{331C}', "\u{30B1}\u{30FC}\u{30B9}"),                  //  ㌜	331C	ケース
+        ('\u{331D}', "\u{30B3}\u{30EB}\u{30CA}"),                  //  ㌝	331D	コルナ
+        ('\u{331E}', "\u{30B3}\u{30FC}\u{30DD}"),                  //  ㌞	331E	コーポ
+        ('\u{331F}', "\u{30B5}\u{30A4}\u{30AF}\u{30EB}"),          //  ㌟	331F	サイクル
+        ('\u{3320}', "\u{30B5}\u{30F3}\u{30C1}\u{30FC}\u{30E0}"),  //  ㌠	3320	サンチーム
+        ('\u{3321}', "\u{30B7}\u{30EA}\u{30F3}\u{30B0}"),          //  ㌡	3321	シリング
+        ('\u{3322}', "\u{30BB}\u{30F3}\u{30C1}"),                  //  ㌢	3322	センチ
+        ('\u{3323}', "\u{30BB}\u{30F3}\u{30C8}"),                  //  ㌣	3323	セント
+        ('\u{3324}', "\u{30C0}\u{30FC}\u{30B9}"),                  //  ㌤	3324	ダース
+        ('\u{3325}', "\u{30C7}\u{30B7}"),                          //  ㌥	3325	デシ
+        ('\u{3326}', "\u{30C9}\u{30EB}"),                          //  ㌦	3326	ドル
+        ('\u{3327}', "\u{30C8}\u{30F3}"),                          //  ㌧	3327	トン
+        ('\u{3328}', "\u{30CA}\u{30CE}"),                          //  ㌨	3328	ナノ
+        ('\u{3329}', "\u{30CE}\u{30C3}\u{30C8}"),                  //  ㌩	3329	ノット
+        ('\u{332A}', "\u{30CF}\u{30A4}\u{30C4}"),                  //  ㌪	332A	ハイツ
+        ('\u{332B}', "\u{30D1}\u{30FC}\u{30BB}\u{30F3}\u{30C8}"),  //  ㌫	332B	パーセント
+        ('\u{332C}', "\u{30D1}\u{30FC}\u{30C4}"),                  //  ㌬	332C	パーツ
+        ('\u{332D}', "\u{30D0}\u{30FC}\u{30EC}\u{30EB}"),          //  ㌭	332D	バーレル
+        ('\u{332E}', "\u{30D4}\u{30A2}\u{30B9}\u{30C8}\u{30EB}"),  //  ㌮	332E	ピアストル
+        ('\u{332F}', "\u{30D4}\u{30AF}\u{30EB}"),                  //  ㌯	332F	ピクル
+        ('\u{3330}', "\u{30D4}\u{30B3}"),                          //  ㌰	3330	ピコ
+        ('\u{3331}', "\u{30D3}\u{30EB}"),                          //  ㌱	3331	ビル
+        ('\u{3332}', "\u{30D5}\u{30A1}\u{30E9}\u{30C3}\u{30C9}"),  //  ㌲	3332	ファラッド
+        ('\u{3333}', "\u{30D5}\u{30A3}\u{30FC}\u{30C8}"),          //  ㌳	3333	フィート
+        ('\u{3334}', "\u{30D6}\u{30C3}\u{30B7}\u{30A7}\u{30EB}"),  //  ㌴	3334	ブッシェル
+        ('\u{3335}', "\u{30D5}\u{30E9}\u{30F3}"),                  //  ㌵	3335	フラン
+        ('\u{3336}', "\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}"),  //  ㌶	3336	ヘクタール
+        ('\u{3337}', "\u{30DA}\u{30BD}"),                          //  ㌷	3337	ペソ
+        ('\u{3338}', "\u{30DA}\u{30CB}\u{30D2}"),                  //  ㌸	3338	ペニヒ
+        ('\u{3339}', "\u{30D8}\u{30EB}\u{30C4}"),                  //  ㌹	3339	ヘルツ
+        ('\u{333A}', "\u{30DA}\u{30F3}\u{30B9}"),                  //  ㌺	333A	ペンス
+        ('\u{333B}', "\u{30DA}\u{30FC}\u{30B8}"),                  //  ㌻	333B	ページ
+        ('\u{333C}', "\u{30D9}\u{30FC}\u{30BF}"),                  //  ㌼	333C	ベータ
+        ('\u{333D}', "\u{30DD}\u{30A4}\u{30F3}\u{30C8}"),          //  ㌽	333D	ポイント
+        ('\u{333E}', "\u{30DC}\u{30EB}\u{30C8}"),                  //  ㌾	333E	ボルト
+        ('\u{333F}', "\u{30DB}\u{30F3}"),                          //  ㌿	333F	ホン
+        ('\u{3340}', "\u{30DD}\u{30F3}\u{30C9}"),                  //  ㍀	3340	ポンド
+        ('\u{3341}', "\u{30DB}\u{30FC}\u{30EB}"),                  //  ㍁	3341	ホール
+        ('\u{3342}', "\u{30DB}\u{30FC}\u{30F3}"),                  //  ㍂	3342	ホーン
+        ('\u{3343}', "\u{30DE}\u{30A4}\u{30AF}\u{30ED}"),          //  ㍃	3343	マイクロ
+        ('\u{3344}', "\u{30DE}\u{30A4}\u{30EB}"),                  //  ㍄	3344	マイル
+        ('\u{3345}', "\u{30DE}\u{30C3}\u{30CF}"),                  //  ㍅	3345	マッハ
+        ('\u{3346}', "\u{30DE}\u{30EB}\u{30AF}"),                  //  ㍆	3346	マルク
+        ('\u{3347}', "\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}"),  //  ㍇	3347	マンション
+        ('\u{3348}', "\u{30DF}\u{30AF}\u{30ED}\u{30F3}"),          //  ㍈	3348	ミクロン
+        ('\u{3349}', "\u{30DF}\u{30EA}"),                          //  ㍉	3349	ミリ
+        ('\u{334A}', "\u{30DF}\u{30EA}\u{30D0}\u{30FC}\u{30EB}"),  //  ㍊	334A	ミリバール
+        ('\u{334B}', "\u{30E1}\u{30AC}"),                          //  ㍋	334B	メガ
+        ('\u{334C}', "\u{30E1}\u{30AC}\u{30C8}\u{30F3}"),          //  ㍌	334C	メガトン
+        ('\u{334D}', "\u{30E1}\u{30FC}\u{30C8}\u{30EB}"),          //  ㍍	334D	メートル
+        ('\u{334E}', "\u{30E4}\u{30FC}\u{30C9}"),                  //  ㍎	334E	ヤード
+        ('\u{334F}', "\u{30E4}\u{30FC}\u{30EB}"),                  //  ㍏	334F	ヤール
+        ('\u{3350}', "\u{30E6}\u{30A2}\u{30F3}"),                  //  ㍐	3350	ユアン
+        ('\u{3351}', "\u{30EA}\u{30C3}\u{30C8}\u{30EB}"),          //  ㍑	3351	リットル
+        ('\u{3352}', "\u{30EA}\u{30E9}"),                          //  ㍒	3352	リラ
+        ('\u{3353}', "\u{30EB}\u{30D4}\u{30FC}"),                  //  ㍓	3353	ルピー
+        ('\u{3354}', "\u{30EB}\u{30FC}\u{30D6}\u{30EB}"),          //  ㍔	3354	ルーブル
+        ('\u{3355}', "\u{30EC}\u{30E0}"),                          //  ㍕	3355	レム
+        ('\u{3356}', "\u{30EC}\u{30F3}\u{30C8}\u{30B2}\u{30F3}"),  //  ㍖	3356	レントゲン
+        ('\u{3357}', "\u{30EF}\u{30C3}\u{30C8}"),                  //  ㍗	3357	ワット
+    ].iter().copied().collect();
+}
+
+/// Expand squared and circled Katakana into normal ones  [㌔㍉㋐ -> キロミリア]
+///
+/// With Width::Half, the expanded Katakana are converted by kata2half().
+/// # Examples
+/// ```
+/// use kana::Width;
+/// assert_eq!("5キロ 3センチ ア", kana::expand_squared("5㌔ 3㌢ ㋐", Width::Full));
+/// assert_eq!("5ｷﾛ 3ｾﾝﾁ ｱ", kana::expand_squared("5㌔ 3㌢ ㋐", Width::Half));
+/// ```
+pub fn expand_squared(s: &str, width: Width) -> String {
+    let mut line = String::with_capacity(s.len() * 3);
+    for c in s.chars() {
+        match (SQUARES.get(&c), width) {
+            (Some(k), Width::Full) => line.push_str(k),
+            (Some(k), Width::Half) => line.push_str(&kata2half(k)),
+            (None, _)              => line.push(c),
+        }
+    }
+    line
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squared_t1() {
+        assert_eq!(0x3358 - 0x3300 + 0x32FF - 0x32D0, SQUARES.len());
+        assert_eq!("アパート ヲ", expand_squared("㌀ ㋾", Width::Full));
+        assert_eq!("ｱﾊﾟｰﾄ ｦ", expand_squared("㌀ ㋾", Width::Half));
+        assert_eq!("ｷﾛ キロ", expand_squared("㌔ キロ", Width::Half));
+    }
+}
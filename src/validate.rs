@@ -0,0 +1,129 @@
+//! Validation of form fields restricted to a script, with fixes by the converters of this crate
+
+use {is_hiragana, is_katakana, is_halfwidth_kana, is_fullwidth_ascii, is_voiced_mark,
+     half2kana, hira2kata, kata2hira, wide2ascii, combine, space2wide};
+
+/// Character sets of fields checked by validate()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Allowed {
+    /// Katakana, Prolonged-sound-marks and Wide-spaces, as for Furigana  [フリガナ]
+    Katakana,
+    /// Hiragana, Prolonged-sound-marks and Wide-spaces  [ふりがな]
+    Hiragana,
+    /// ASCII digits and Latin letters  [Abc123]
+    HalfwidthAlnum,
+}
+
+/// Converters which fix violations found by validate()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fix {
+    /// half2kana()  [ｶﾅ -> カナ]
+    Half2Kana,
+    /// hira2kata()  [かな -> カナ]
+    Hira2Kata,
+    /// kata2hira()  [カナ -> かな]
+    Kata2Hira,
+    /// combine()  [か\u{3099} -> が]
+    Combine,
+    /// space2wide()  [\u{20} -> \u{3000}]
+    Space2Wide,
+    /// wide2ascii()  [Ａ１ -> A1]
+    Wide2Ascii,
+}
+
+impl Fix {
+    /// Apply the converter to a string
+    /// # Examples
+    /// ```
+    /// assert_eq!("カナ", kana::Fix::Half2Kana.apply("ｶﾅ"));
+    /// ```
+    pub fn apply(self, s: &str) -> String {
+        match self {
+            Fix::Half2Kana  => half2kana(s),
+            Fix::Hira2Kata  => hira2kata(s),
+            Fix::Kata2Hira  => kata2hira(s),
+            Fix::Combine    => combine(s),
+            Fix::Space2Wide => space2wide(s),
+            Fix::Wide2Ascii => wide2ascii(s),
+        }
+    }
+}
+
+/// A character not allowed in a field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    /// Byte offset of the character in the source
+    pub byte: usize,
+    /// Character offset of the character in the source
+    pub index: usize,
+    /// The character
+    pub ch: char,
+    /// Converter which fixes the character, if any
+    pub fix: Option<Fix>,
+}
+
+//  Whether a character is allowed, and the fix if not
+fn check(c: char, allowed: Allowed) -> Result<(), Option<Fix>> {
+    let common = c == '\u{3000}' || c == 'ー';
+    match allowed {
+        Allowed::Katakana if is_katakana(c) || common => Ok(()),
+        Allowed::Hiragana if is_hiragana(c) || common => Ok(()),
+        Allowed::HalfwidthAlnum if c.is_ascii_alphanumeric() => Ok(()),
+        Allowed::Katakana | Allowed::Hiragana => Err(match c {
+            _ if is_voiced_mark(c) && !is_halfwidth_kana(c) => Some(Fix::Combine),
+            _ if is_halfwidth_kana(c) => Some(Fix::Half2Kana),
+            ' ' => Some(Fix::Space2Wide),
+            _ if allowed == Allowed::Katakana && is_hiragana(c) => Some(Fix::Hira2Kata),
+            _ if allowed == Allowed::Hiragana && is_katakana(c) &&
+                 is_hiragana(kata2hira(&c.to_string()).chars().next().unwrap()) => Some(Fix::Kata2Hira),
+            _ => None,
+        }),
+        Allowed::HalfwidthAlnum => Err(match c {
+            _ if is_fullwidth_ascii(c) && wide2ascii(&c.to_string()).chars().all(|x| x.is_ascii_alphanumeric()) =>
+                Some(Fix::Wide2Ascii),
+            _ => None,
+        }),
+    }
+}
+
+/// Find the characters not allowed in a field, with their offsets and fixes  [ｶﾅ -> Half2Kana]
+///
+/// Half-width-kana in a Hiragana field are fixed by Half2Kana into Katakana,
+/// which are then fixed by Kata2Hira.
+/// # Examples
+/// ```
+/// use kana::{Allowed, Fix};
+/// assert!(kana::validate("フリガナ　タロー", Allowed::Katakana).is_ok());
+/// let vs = kana::validate("ふりｶﾞナ", Allowed::Katakana).unwrap_err();
+/// let fixes: Vec<_> = vs.iter().map(|v| (v.byte, v.index, v.fix)).collect();
+/// assert_eq!(vec![(0, 0, Some(Fix::Hira2Kata)), (3, 1, Some(Fix::Hira2Kata)),
+///                 (6, 2, Some(Fix::Half2Kana)), (9, 3, Some(Fix::Half2Kana))], fixes);
+/// ```
+pub fn validate(s: &str, allowed: Allowed) -> Result<(), Vec<Violation>> {
+    let violations: Vec<_> = s.char_indices().enumerate()
+        .filter_map(|(index, (byte, ch))| match check(ch, allowed) {
+            Ok(()) => None,
+            Err(fix) => Some(Violation { byte, index, ch, fix }),
+        })
+        .collect();
+    if violations.is_empty() { Ok(()) } else { Err(violations) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_t1() {
+        let fixes = |s, a| validate(s, a).unwrap_err().iter().map(|v| v.fix).collect::<Vec<_>>();
+        assert_eq!(vec![Some(Fix::Kata2Hira), Some(Fix::Space2Wide), None],
+                   fixes("やまダ 太", Allowed::Hiragana));
+        assert_eq!(vec![None, Some(Fix::Kata2Hira), Some(Fix::Combine)],
+                   fixes("ヷゐカ\u{3099}", Allowed::Hiragana));
+        assert_eq!(vec![Some(Fix::Wide2Ascii), None, None],
+                   fixes("Ａbc-1!", Allowed::HalfwidthAlnum));
+        assert!(validate("", Allowed::HalfwidthAlnum).is_ok());
+        assert!(validate("ヴァヶㇰー", Allowed::Katakana).is_ok());
+    }
+}
@@ -0,0 +1,124 @@
+//! Character set of Zengin[全銀] format for bank transfer files
+//!
+//! Zengin allows Half-width-kana in upper case with ﾞﾟ, digits, Latin capitals,
+//! space and the symbols `( ) - . / ｢ ｣ \ ,`.
+
+use std::error::Error;
+use std::fmt;
+use {wide2ascii, hira2kata, small2large, kata2half, is_voiced_mark,
+     CH_VOICED_COMBI, CH_SEMIVOICED_COMBI, CH_VOICED_FULL, CH_VOICED_HALF, CH_SEMIVOICED_HALF};
+
+/// A character which cannot be converted into Zengin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZenginError {
+    /// Byte offset of the character in the source
+    pub byte: usize,
+    /// Character offset of the character in the source
+    pub index: usize,
+    /// The character
+    pub ch: char,
+}
+
+impl fmt::Display for ZenginError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}' at character {} (byte {}) is not allowed in Zengin", self.ch, self.index, self.byte)
+    }
+}
+
+impl Error for ZenginError {}
+
+/// Whether a character is in the Zengin character set  [ｱ, ﾞ, A, 1, ｢]
+/// # Examples
+/// ```
+/// assert!(kana::zengin::is_allowed('ｶ') && !kana::zengin::is_allowed('ｯ'));
+/// ```
+pub fn is_allowed(c: char) -> bool {
+    matches!(c, 'ｱ'..='ﾝ' | 'ｦ' | CH_VOICED_HALF | CH_SEMIVOICED_HALF |
+                '0'..='9' | 'A'..='Z' | ' ' | '(' | ')' | '-' | '.' | '/' |
+                '｢' | '｣' | '\\' | ',')
+}
+
+//  Zengin characters for a character, or None
+fn convert_char(c: char) -> Option<String> {
+    let t = match c {
+        'ー' | 'ｰ' | '－' | '‐' | '−' | '—' | '―' => "-".to_string(),
+        '「' => "｢".to_string(),
+        '」' => "｣".to_string(),
+        '¥' | '￥' => "\\".to_string(),
+        '\u{3000}' => " ".to_string(),
+        _ if is_voiced_mark(c) => match c {
+            CH_VOICED_COMBI | CH_VOICED_FULL | CH_VOICED_HALF => CH_VOICED_HALF.to_string(),
+            _ => CH_SEMIVOICED_HALF.to_string(),
+        },
+        _ => {
+            let s = c.to_string();
+            kata2half(&small2large(&hira2kata(&wide2ascii(&s)))).to_ascii_uppercase()
+        },
+    };
+    if t.chars().all(is_allowed) { Some(t) } else { None }
+}
+
+/// Convert a string into Zengin characters  [ｷｬﾂﾌﾟ ﾀﾛｰ -> ｷﾔﾂﾌﾟ ﾀﾛ-]
+///
+/// Hiragana and Katakana become Half-width-kana, small kana become large ones,
+/// and Prolonged-sound-marks become hyphens. Wide-alphanumerics become ASCII and letters become upper case.
+/// Voiced-sound-marks in any style become ﾞﾟ, dropping the space of " \u{3099}".
+/// All the characters which cannot be converted are returned as errors, at their positions in the source.
+/// # Examples
+/// ```
+/// assert_eq!(Ok("ﾔﾏﾀﾞ ﾀﾛ-".to_string()), kana::zengin::convert("やまだ　たろー"));
+/// assert_eq!(Ok("ｶ)ｷﾔﾂﾌﾟ".to_string()), kana::zengin::convert("ｶ)キャップ"));
+/// let e = kana::zengin::convert("山田 タロウ").unwrap_err();
+/// assert_eq!(vec![('山', 0, 0), ('田', 1, 3)], e.iter().map(|x| (x.ch, x.index, x.byte)).collect::<Vec<_>>());
+/// ```
+pub fn convert(s: &str) -> Result<String, Vec<ZenginError>> {
+    let mut line = String::with_capacity(s.len());
+    let mut errors = Vec::new();
+    let cs: Vec<(usize, char)> = s.char_indices().collect();
+    for (index, &(byte, ch)) in cs.iter().enumerate() {
+        let mark = cs.get(index + 1).map(|x| x.1);
+        if ch == ' ' && (mark == Some(CH_VOICED_COMBI) || mark == Some(CH_SEMIVOICED_COMBI)) {
+            continue;
+        }
+        match convert_char(ch) {
+            Some(t) => line.push_str(&t),
+            None    => errors.push(ZenginError { byte, index, ch }),
+        }
+    }
+    if errors.is_empty() { Ok(line) } else { Err(errors) }
+}
+
+/// Check that a string consists of Zengin characters only
+/// # Examples
+/// ```
+/// assert!(kana::zengin::validate("ﾔﾏﾀﾞ ﾀﾛｳ").is_ok());
+/// assert_eq!(1, kana::zengin::validate("ﾔﾏﾀﾞ ﾀﾛｰ").unwrap_err().len());
+/// ```
+pub fn validate(s: &str) -> Result<(), Vec<ZenginError>> {
+    let errors: Vec<_> = s.char_indices().enumerate()
+        .filter(|&(_, (_, ch))| !is_allowed(ch))
+        .map(|(index, (byte, ch))| ZenginError { byte, index, ch })
+        .collect();
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zengin_t1() {
+        assert_eq!(Ok("ｶﾞﾂｺｳ".to_string()), convert("がっこう"));
+        assert_eq!(Ok("ｳﾞｲｴ ABC-1 ｲｴｶｹ".to_string()), convert("ヴィエ abc－1 ヰヱヵヶ"));
+        assert_eq!(Ok("ｶﾞﾊﾟ \\100".to_string()), convert("か\u{3099}ハ゜ ￥100"));
+        assert_eq!(Ok("｢ｶ｣.,/()".to_string()), convert("「カ」.,/()"));
+        let e = convert("ア、イ。").unwrap_err();
+        assert_eq!(vec![3, 9], e.iter().map(|x| x.byte).collect::<Vec<_>>());
+        assert_eq!("'、' at character 1 (byte 3) is not allowed in Zengin", e[0].to_string());
+        assert!(convert("").is_ok());
+        assert_eq!(Ok("ｱﾞ ﾊﾟ".to_string()), convert(&::half2kana("ｱﾞ ﾊﾟ")));
+        let e = convert("カ \u{3099}漢").unwrap_err();
+        assert_eq!(vec![('漢', 3, 7)], e.iter().map(|x| (x.ch, x.index, x.byte)).collect::<Vec<_>>());
+    }
+}